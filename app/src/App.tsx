@@ -15,6 +15,22 @@ import '@solana/wallet-adapter-react-ui/styles.css';
 export type GameView = 'home' | 'create' | 'join' | 'play' | 'result' | 'profile';
 export type Move = 'rock' | 'paper' | 'scissors';
 export type WagerAmount = 'sol001' | 'sol01' | 'sol1';
+
+// Stake presets offered by the app, in lamports (the program accepts any wager within its min/max)
+export const WAGER_LAMPORTS: Record<WagerAmount, number> = {
+  sol001: 10_000_000,
+  sol01: 100_000_000,
+  sol1: 1_000_000_000,
+};
+
+export const wagerFromLamports = (lamports: number): WagerAmount | undefined =>
+  (Object.keys(WAGER_LAMPORTS) as WagerAmount[]).find(wager => WAGER_LAMPORTS[wager] === lamports);
+
+// A game's wager: one of the presets, or the lamport amount of a game staked outside them
+export type GameWager = WagerAmount | number;
+
+export const wagerLamports = (wager: GameWager): number =>
+  typeof wager === 'number' ? wager : WAGER_LAMPORTS[wager];
 export type GameStatus = 'WaitingForPlayer' | 'CommitPhase' | 'RevealPhase' | 'Finished';
 
 export interface Game {
@@ -23,7 +39,8 @@ export interface Game {
   player1Name?: string;
   player2?: string;
   player2Name?: string;
-  wager: WagerAmount;
+  wager: GameWager;
+  feePerPlayer?: number; // Lamports, locked when the game was created
   status: GameStatus;
   player1Move?: Move;
   player2Move?: Move;
@@ -50,7 +67,7 @@ function AppContent() {
     setCurrentView('play');
   };
 
-  const handleJoinGame = async (gameId: string, wager: WagerAmount) => {
+  const handleJoinGame = async (gameId: string, wager: GameWager) => {
     // Blockchain interaction is now handled in JoinGame.tsx
     // This function just handles UI state after successful join
     
@@ -74,13 +91,7 @@ function AppContent() {
     setCurrentGame(null);
   };
 
-  const getWagerDisplay = (wager: WagerAmount): string => {
-    switch (wager) {
-      case 'sol001': return '0.01 SOL';
-      case 'sol01': return '0.1 SOL';
-      case 'sol1': return '1 SOL';
-    }
-  };
+  const getWagerDisplay = (wager: GameWager): string => `${wagerLamports(wager) / 1_000_000_000} SOL`;
 
 
 
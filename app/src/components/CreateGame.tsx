@@ -1,10 +1,11 @@
 import { useState } from 'react';
-import { WagerAmount } from '../App';
+import { WagerAmount, WAGER_LAMPORTS } from '../App';
 import { ArrowLeft, DollarSign } from 'lucide-react';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useConnection } from '@solana/wallet-adapter-react';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
 import idl from '../idl/crypto_pvp.json';
+import { fetchReferralAccounts } from '../utils/referralAccounts';
 
 interface CreateGameProps {
   onCreateGame: (_wager: WagerAmount, _gameId?: string) => void;
@@ -32,13 +33,12 @@ export function CreateGame({ onCreateGame, onBack }: CreateGameProps) {
     { value: 5, emoji: '👑', name: 'Championship', description: 'Best of 5 rounds' },
   ];
 
-  const getWagerEnum = (wager: WagerAmount) => {
-    switch (wager) {
-      case 'sol001': return { sol001: {} };
-      case 'sol01': return { sol01: {} }; 
-      case 'sol1': return { sol1: {} };
-      default: return { sol01: {} };
-    }
+  // Fee per player the program will lock at current settings (rounded up like on-chain)
+  const getExpectedFee = (globalState: any, wager: BN) => {
+    let fee = wager.mul(new BN(globalState.feeBps)).add(new BN(9_999)).div(new BN(10_000));
+    fee = BN.max(fee, globalState.minFee);
+    if (!globalState.maxFee.isZero()) fee = BN.min(fee, globalState.maxFee);
+    return fee;
   };
 
   const handleCreateGame = async () => {
@@ -60,7 +60,9 @@ export function CreateGame({ onCreateGame, onBack }: CreateGameProps) {
       
       const globalState = await (program.account as any).globalState.fetch(globalStatePda);
       const gameCounter = (globalState as any).gameCounter.toNumber();
-      const feeCollector = (globalState as any).feeCollector;
+      const wager = new BN(WAGER_LAMPORTS[selectedWager]);
+      // Abort if the fee changes before the transaction lands
+      const maxFee = getExpectedFee(globalState, wager);
       
       const [gamePda] = await web3.PublicKey.findProgramAddress([
         Buffer.from('game'),
@@ -74,15 +76,22 @@ export function CreateGame({ onCreateGame, onBack }: CreateGameProps) {
       
       console.log('🎮 Creating game with wager:', selectedWager);
       
-      // Send transaction - only pass wager, no move hash
+      // Send transaction - public SOL game paid from the wallet, no move hash
       await program.methods.createGame(
-        getWagerEnum(selectedWager)
-      ).accounts({
+        wager,
+        selectedRounds,
+        null, // No invited player
+        null, // No join password
+        maxFee
+      ).accountsPartial({
         game: gamePda,
         globalState: globalStatePda,
         player: wallet.publicKey,
         player1Profile: playerProfilePda,
-        feeCollector: feeCollector,
+        ...(await fetchReferralAccounts(program, wallet.publicKey)),
+        wagerTier: null, // Free wager within the global min/max
+        fundsVault: null,
+        playerMintStats: null,
         systemProgram: web3.SystemProgram.programId,
       }).rpc();
       
@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft, Clock, Eye, EyeOff } from 'lucide-react';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
@@ -15,7 +15,7 @@ import { GamePlayResults } from './GamePlayResults';
 interface GamePlayProps {
   game: Game;
   onBack: () => void;
-  getWagerDisplay: (_wager: WagerAmount) => string;
+  getWagerDisplay: (_wager: GameWager) => string;
   playerAddress: string;
   rejoinToReveal?: boolean;
   onClearRejoinToReveal?: () => void;
@@ -1,4 +1,4 @@
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft } from 'lucide-react';
 
 interface GamePlayResultsProps {
@@ -6,7 +6,7 @@ interface GamePlayResultsProps {
   mySelectedMove: 'rock' | 'paper' | 'scissors';
   opponentMove: 'rock' | 'paper' | 'scissors';
   onBack: () => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function GamePlayResults({
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft } from 'lucide-react';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
@@ -20,7 +20,7 @@ interface GamePlayRevealProps {
   onRevealSuccess: (move: 'rock' | 'paper' | 'scissors') => void;
   onGameDataUpdate: (updatedGame: Game) => void;
   onSetManualInput: (input: { move: 'rock' | 'paper' | 'scissors'; salt: string; isVisible: boolean }) => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function GamePlayReveal({
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft } from 'lucide-react';
 import { useWallet, useConnection } from '@solana/wallet-adapter-react';
 import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
@@ -31,7 +31,7 @@ interface GamePlayRockPaperScissorsProps {
   onBack: () => void;
   onMoveCommitted: (move: 'rock' | 'paper' | 'scissors', saltHex: string) => void;
   onGameDataUpdate: (updatedGame: Game) => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function GamePlayRockPaperScissors({
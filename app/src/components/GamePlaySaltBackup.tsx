@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft, Copy, Check } from 'lucide-react';
 
 interface GamePlaySaltBackupProps {
@@ -10,7 +10,7 @@ interface GamePlaySaltBackupProps {
   salt: string;
   onBack: () => void;
   onGoToReveal: () => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function GamePlaySaltBackup({
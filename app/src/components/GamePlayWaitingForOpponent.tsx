@@ -1,11 +1,11 @@
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { ArrowLeft } from 'lucide-react';
 
 interface GamePlayWaitingForOpponentProps {
   currentGameData: Game;
   mySelectedMove: 'rock' | 'paper' | 'scissors' | null;
   onBack: () => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function GamePlayWaitingForOpponent({
@@ -1,11 +1,11 @@
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager, wagerLamports } from '../App';
 import { Trophy, RotateCcw, Home } from 'lucide-react';
 
 interface GameResultProps {
   game: Game;
   onPlayAgain: () => void;
   onBackToHome: () => void;
-  getWagerDisplay: (_wager: WagerAmount) => string;
+  getWagerDisplay: (_wager: GameWager) => string;
   playerAddress: string;
 }
 
@@ -52,13 +52,7 @@ export function GameResult({ game, onPlayAgain, onBackToHome, getWagerDisplay, p
     }
   };
 
-  const getDoubleWager = (wager: WagerAmount): string => {
-    switch (wager) {
-      case 'sol1': return '2.0 SOL';
-      case 'sol01': return '0.2 SOL';
-      case 'sol001': return '0.02 SOL';
-    }
-  };
+  const getDoubleWager = (wager: GameWager): string => `${(wagerLamports(wager) * 2) / 1_000_000_000} SOL`;
 
   const resultInfo = getResultInfo();
 
@@ -1,5 +1,5 @@
 import { Plus, Users, Trophy, Play } from 'lucide-react';
-import { Game, WagerAmount } from '../App';
+import { Game, GameWager } from '../App';
 import { useGetMyActiveGames } from '../utils/gameDataHooks';
 import { useWallet } from '@solana/wallet-adapter-react';
 
@@ -7,7 +7,7 @@ interface HomeProps {
   onCreateGame: () => void;
   onJoinGame: () => void;
   onRejoinGame: (game: Game) => void;
-  getWagerDisplay: (wager: WagerAmount) => string;
+  getWagerDisplay: (wager: GameWager) => string;
 }
 
 export function Home({ 
@@ -1,18 +1,19 @@
 import { useState } from 'react';
-import { WagerAmount } from '../App';
+import { WagerAmount, GameWager, wagerLamports } from '../App';
 import { ArrowLeft, Users, Clock, Filter, Plus } from 'lucide-react';
 import { useGetAvailableGames } from '../utils/gameDataHooks';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { useConnection } from '@solana/wallet-adapter-react';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
 import idl from '../idl/crypto_pvp.json';
+import { fetchReferralAccounts } from '../utils/referralAccounts';
 import { WalletMultiButton } from '@solana/wallet-adapter-react-ui';
 
 interface JoinGameProps {
-  onJoinGame: (_gameId: string, _wager: WagerAmount) => void;
+  onJoinGame: (_gameId: string, _wager: GameWager) => void;
   onBack: () => void;
   onCreateGame: () => void;
-  getWagerDisplay: (_wager: WagerAmount) => string;
+  getWagerDisplay: (_wager: GameWager) => string;
 }
 
 const PROGRAM_ID = new web3.PublicKey(idl.address);
@@ -66,9 +67,13 @@ export function JoinGame({ onJoinGame, onBack, onCreateGame, getWagerDisplay }:
         Buffer.from('global_state')
       ], PROGRAM_ID);
       
-      // Fetch global state to get fee collector
-      const globalState = await (program.account as any).globalState.fetch(globalStatePda);
-      const feeCollector = (globalState as any).feeCollector;
+      const selectedGameData = availableGamesToShow.find(g => g.id === selectedGame);
+      if (!selectedGameData) {
+        throw new Error('Selected game data not found');
+      }
+      
+      // The fee shown in the game details is the most the player agreed to pay
+      const maxFee = new BN(selectedGameData.feePerPlayer ?? 0);
       
       const [playerProfilePda] = await web3.PublicKey.findProgramAddress([
         Buffer.from('player_profile'),
@@ -77,25 +82,27 @@ export function JoinGame({ onJoinGame, onBack, onCreateGame, getWagerDisplay }:
       
       console.log('🎮 Joining game:', selectedGame);
       
-      // Send transaction - only pass game_id, no move hash
+      // Send transaction - the program rejects the join if the wager differs from the one shown
       await program.methods.joinGame(
-        gameIdBN  // Only _game_id parameter
-      ).accounts({
+        gameIdBN,
+        null, // Public game, no join password
+        new BN(wagerLamports(selectedGameData.wager)),
+        maxFee
+      ).accountsPartial({
         game: gamePda,
         globalState: globalStatePda,
         player: wallet.publicKey,
         player2Profile: playerProfilePda,
-        feeCollector: feeCollector,
+        ...(await fetchReferralAccounts(program, wallet.publicKey)),
+        joinTicket: null,
+        fundsVault: null,
+        playerMintStats: null,
         systemProgram: web3.SystemProgram.programId,
       }).rpc();
       
       console.log('✅ Successfully joined game:', selectedGame);
       
       // Call parent handler for UI state (no move since we don't commit during join)
-      const selectedGameData = availableGamesToShow.find(g => g.id === selectedGame);
-      if (!selectedGameData) {
-        throw new Error('Selected game data not found');
-      }
       await onJoinGame(selectedGame, selectedGameData.wager);
     } catch (e) {
       console.error('Error details:', e);
@@ -253,6 +260,10 @@ export function JoinGame({ onJoinGame, onBack, onCreateGame, getWagerDisplay }:
               <span className="text-secondary">Wager Amount:</span>
               <span className="font-semibold text-accent">{getWagerDisplay(selectedGameData.wager)}</span>
             </div>
+            <div className="flex justify-between items-center p-3 rounded-lg" style={{background: 'rgba(255,255,255,0.05)'}}>
+              <span className="text-secondary">Fee:</span>
+              <span className="font-semibold">{getWagerDisplay(selectedGameData.feePerPlayer ?? 0)}</span>
+            </div>
             <div className="flex justify-between items-center p-3 rounded-lg" style={{background: 'rgba(255,255,255,0.05)'}}>
               <span className="text-secondary">Opponent:</span>
               <span className="font-semibold">{selectedGameData.player1Name || `${selectedGameData.player1.slice(0, 8)}...`}</span>
@@ -5,6 +5,7 @@ import { Program, AnchorProvider, web3 } from '@coral-xyz/anchor';
 import idl from '../idl/crypto_pvp.json';
 import { fetchPlayerName } from '../utils/fetchGames';
 import { fetchMyActiveGames } from '../utils/fetchGames';
+import { wagerLamports } from '../App';
 import Tippy from '@tippyjs/react';
 import { followCursor } from 'tippy.js';
 import 'tippy.js/dist/tippy.css';
@@ -163,9 +164,7 @@ export function Profile({ onBack }: ProfileProps) {
             gameId: parseInt(gameData.gameId.toString()),
             opponent,
             opponentName,
-            wager: gameData.wager.sol1 ? 1_000_000_000 : 
-                   gameData.wager.sol01 ? 100_000_000 : 
-                   gameData.wager.sol001 ? 10_000_000 : 0,
+            wager: gameData.wager.toNumber(), // Lamports
             result,
             myMove: moveToString(myMove),
             opponentMove: moveToString(opponentMove),
@@ -195,10 +194,7 @@ export function Profile({ onBack }: ProfileProps) {
       const games = await fetchMyActiveGames(connection, wallet, publicKey.toString());
       let sum = 0;
       for (const game of games) {
-        // Convert wager enum to lamports
-        if (game.wager === 'sol1') sum += 1_000_000_000;
-        else if (game.wager === 'sol01') sum += 100_000_000;
-        else if (game.wager === 'sol001') sum += 10_000_000;
+        sum += wagerLamports(game.wager);
       }
       setOpenWagers(sum);
     };
@@ -14,19 +14,69 @@ export type CryptoPvp = {
   },
   "instructions": [
     {
-      "name": "claimTimeoutVictory",
+      "name": "acceptAuthority",
       "docs": [
-        "Claim victory when opponent fails to reveal within timeout"
+        "Second step of an authority transfer: the proposed authority takes over by signing,",
+        "which proves the address is controlled by someone"
       ],
       "discriminator": [
-        21,
-        175,
-        209,
-        185,
+        107,
+        86,
+        198,
+        91,
+        33,
+        12,
+        107,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAuthority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptRematch",
+      "docs": [
+        "Accept a rematch offer; works like join_game but only for rematch games.",
+        "The game's locked wager and fee must match the terms the player signed for."
+      ],
+      "discriminator": [
+        139,
         79,
-        50,
-        147,
-        140
+        31,
+        212,
+        167,
+        74,
+        60,
+        165
       ],
       "accounts": [
         {
@@ -77,10 +127,11 @@ export type CryptoPvp = {
         },
         {
           "name": "player",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "player1Profile",
+          "name": "player2Profile",
           "writable": true,
           "pda": {
             "seeds": [
@@ -105,96 +156,153 @@ export type CryptoPvp = {
               },
               {
                 "kind": "account",
-                "path": "game.player1",
-                "account": "game"
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "player2Profile",
+          "name": "referralAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
-                  97,
-                  121,
+                  114,
+                  101,
+                  102,
                   101,
                   114,
-                  95,
-                  112,
                   114,
-                  111,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referral_account.referrer",
+                "account": "referralAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralLink",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
                   102,
-                  105,
+                  101,
+                  114,
+                  114,
+                  97,
                   108,
-                  101
+                  95,
+                  108,
+                  105,
+                  110,
+                  107
                 ]
               },
               {
                 "kind": "account",
-                "path": "game.player2",
-                "account": "game"
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "player1",
-          "writable": true
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "player2",
-          "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "commitMove",
-      "discriminator": [
-        27,
-        16,
-        69,
-        212,
-        175,
-        110,
-        123,
-        189
-      ],
-      "accounts": [
-        {
-          "name": "game",
+          "name": "fundsVault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
                   97,
-                  109,
-                  101
+                  117,
+                  108,
+                  116
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "player",
-          "signer": true
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "playerTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "associatedTokenProgram",
+              "optional": true,
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            }
+          ]
         },
         {
-          "name": "playerProfile",
+          "name": "playerMintStats",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -207,21 +315,32 @@ export type CryptoPvp = {
                   101,
                   114,
                   95,
-                  112,
-                  114,
-                  111,
-                  102,
+                  109,
                   105,
-                  108,
-                  101
+                  110,
+                  116,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
               },
               {
                 "kind": "account",
                 "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "token"
               }
             ]
           }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -230,46 +349,56 @@ export type CryptoPvp = {
           "type": "u64"
         },
         {
-          "name": "moveHash",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
+          "name": "expectedWager",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeLamports",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "createGame",
+      "name": "addWagerTier",
+      "docs": [
+        "Register a new wager tier with its own fee rate (only authority can call this, or the council",
+        "through an approved AdminChange::AddWagerTier). Tiers start disabled and are enabled, repriced",
+        "or disabled through an AdminChange::WagerTier change."
+      ],
       "discriminator": [
-        124,
-        69,
-        75,
-        66,
-        184,
+        137,
+        34,
+        54,
         220,
-        72,
-        206
+        179,
+        131,
+        164,
+        100
       ],
       "accounts": [
         {
-          "name": "game",
+          "name": "wagerTier",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  119,
                   97,
-                  109,
-                  101
+                  103,
+                  101,
+                  114,
+                  95,
+                  116,
+                  105,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "global_state.game_counter",
+                "path": "global_state.wager_tier_counter",
                 "account": "globalState"
               }
             ]
@@ -301,44 +430,71 @@ export type CryptoPvp = {
           }
         },
         {
-          "name": "player",
-          "writable": true,
-          "signer": true
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "player1Profile",
+          "name": "approvedChange",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
                   97,
-                  121,
-                  101,
-                  114,
-                  95,
-                  112,
-                  114,
-                  111,
-                  102,
+                  100,
+                  109,
                   105,
-                  108,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               },
               {
                 "kind": "account",
-                "path": "player"
+                "path": "approved_change.change_id",
+                "account": "pendingAdminChange"
               }
             ]
           }
         },
         {
-          "name": "feeCollector",
-          "writable": true
+          "name": "proposer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "systemProgram",
@@ -348,109 +504,115 @@ export type CryptoPvp = {
       "args": [
         {
           "name": "wager",
-          "type": {
-            "defined": {
-              "name": "wagerAmount"
-            }
-          }
+          "type": "u64"
+        },
+        {
+          "name": "feeBps",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "initializeGlobalState",
+      "name": "approveAdminChange",
       "docs": [
-        "Initializes the global state - can only be called once due to #[account(init)]"
+        "Approve a queued admin change as a council member"
       ],
       "discriminator": [
-        232,
-        254,
-        209,
-        244,
-        123,
-        89,
-        154,
-        207
+        246,
+        51,
+        148,
+        102,
+        100,
+        116,
+        72,
+        26
       ],
       "accounts": [
         {
-          "name": "globalState",
-          "writable": true,
+          "name": "council",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
-                  108,
-                  111,
-                  98,
                   97,
-                  108,
+                  100,
+                  109,
+                  105,
+                  110,
                   95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
                 ]
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "feeCollector",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "joinGame",
-      "discriminator": [
-        107,
-        112,
-        18,
-        38,
-        56,
-        173,
-        60,
-        128
-      ],
-      "accounts": [
-        {
-          "name": "game",
+          "name": "pendingChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
                   97,
+                  100,
                   109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               },
               {
                 "kind": "arg",
-                "path": "gameId"
+                "path": "changeId"
               }
             ]
           }
         },
+        {
+          "name": "member",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "changeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelAdminChange",
+      "docs": [
+        "Drop a queued admin change before it is executed. Only the authority can call this,",
+        "or, once the council is enabled, the member who queued it (so one member can't veto the rest)."
+      ],
+      "discriminator": [
+        231,
+        68,
+        100,
+        173,
+        150,
+        100,
+        93,
+        57
+      ],
+      "accounts": [
         {
           "name": "globalState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -474,72 +636,95 @@ export type CryptoPvp = {
           }
         },
         {
-          "name": "player",
-          "writable": true,
-          "signer": true
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "player2Profile",
+          "name": "pendingChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
                   97,
-                  121,
-                  101,
-                  114,
-                  95,
-                  112,
-                  114,
-                  111,
-                  102,
+                  100,
+                  109,
                   105,
-                  108,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
                   101
                 ]
               },
               {
-                "kind": "account",
-                "path": "player"
+                "kind": "arg",
+                "path": "changeId"
               }
             ]
           }
         },
         {
-          "name": "feeCollector",
+          "name": "proposer",
           "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "admin",
+          "signer": true
         }
       ],
       "args": [
         {
-          "name": "gameId",
+          "name": "changeId",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "revealMove",
+      "name": "cancelAuthorityTransfer",
+      "docs": [
+        "Withdraw a pending authority transfer (only authority can call this, and only until the",
+        "council is enabled)"
+      ],
       "discriminator": [
-        30,
-        133,
-        198,
-        26,
-        106,
-        44,
-        55,
-        149
+        94,
+        131,
+        125,
+        184,
+        183,
+        24,
+        125,
+        229
       ],
       "accounts": [
         {
-          "name": "game",
+          "name": "globalState",
           "writable": true,
           "pda": {
             "seeds": [
@@ -547,20 +732,75 @@ export type CryptoPvp = {
                 "kind": "const",
                 "value": [
                   103,
+                  108,
+                  111,
+                  98,
                   97,
-                  109,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
                   101
                 ]
-              },
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
               {
-                "kind": "arg",
-                "path": "gameId"
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
               }
             ]
           }
         },
         {
-          "name": "globalState",
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelGame",
+      "docs": [
+        "Cancel a game nobody has joined yet. Closing the game account returns",
+        "the escrowed net wager and the rent deposit to player1; the fee is not refunded",
+        "unless the game still holds it (RefundWithFees tie policy)."
+      ],
+      "discriminator": [
+        121,
+        194,
+        154,
+        118,
+        103,
+        235,
+        149,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "game",
           "writable": true,
           "pda": {
             "seeds": [
@@ -568,24 +808,21 @@ export type CryptoPvp = {
                 "kind": "const",
                 "value": [
                   103,
-                  108,
-                  111,
-                  98,
-                  97,
-                  108,
-                  95,
-                  115,
-                  116,
                   97,
-                  116,
+                  109,
                   101
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
           "name": "player",
+          "writable": true,
           "signer": true
         },
         {
@@ -614,96 +851,154 @@ export type CryptoPvp = {
               },
               {
                 "kind": "account",
-                "path": "game.player1",
-                "account": "game"
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "player2Profile",
+          "name": "fundsVault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
-                  97,
-                  121,
-                  101,
-                  114,
-                  95,
-                  112,
-                  114,
-                  111,
                   102,
-                  105,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
                   108,
-                  101
+                  116
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "game.player2",
-                "account": "game"
               }
             ]
           }
         },
         {
-          "name": "player1",
-          "writable": true
-        },
-        {
-          "name": "player2",
-          "writable": true
-        }
-      ],
-      "args": [
-        {
-          "name": "gameId",
-          "type": "u64"
-        },
-        {
-          "name": "moveChoice",
-          "type": {
-            "defined": {
-              "name": "move"
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
             }
-          }
+          ]
         },
-        {
-          "name": "salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateFeeCollector",
-      "docs": [
-        "Update fee collector address (only authority can call this)"
-      ],
-      "discriminator": [
-        132,
-        54,
-        153,
-        82,
-        79,
-        118,
-        79,
-        212
-      ],
-      "accounts": [
         {
           "name": "globalState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -727,36 +1022,73 @@ export type CryptoPvp = {
           }
         },
         {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "globalState"
-          ]
+          "name": "previousGame",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.previous_game_id",
+                "account": "game"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
-          "name": "newFeeCollector",
-          "type": "pubkey"
+          "name": "gameId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "updateFeePercentage",
+      "name": "claimCommitTimeout",
       "docs": [
-        "Update fee percentage (only authority can call this)"
+        "Claim the pot (or a refund) when the commit deadline passes without both commits"
       ],
       "discriminator": [
-        102,
-        119,
-        197,
-        160,
-        139,
-        102,
-        182,
-        0
+        129,
+        28,
+        246,
+        159,
+        59,
+        172,
+        176,
+        128
       ],
       "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
         {
           "name": "globalState",
           "writable": true,
@@ -783,38 +1115,43 @@ export type CryptoPvp = {
           }
         },
         {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "globalState"
-          ]
-        }
-      ],
-      "args": [
+          "name": "player",
+          "signer": true
+        },
         {
-          "name": "newFeePercentage",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "updatePlayerName",
-      "docs": [
-        "Update player name (can be called anytime)"
-      ],
-      "discriminator": [
-        1,
-        189,
-        54,
-        68,
-        216,
-        13,
-        185,
-        5
-      ],
-      "accounts": [
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player1",
+                "account": "game"
+              }
+            ]
+          }
+        },
         {
-          "name": "playerProfile",
+          "name": "player2Profile",
           "writable": true,
           "pda": {
             "seeds": [
@@ -839,135 +1176,6523 @@ export type CryptoPvp = {
               },
               {
                 "kind": "account",
-                "path": "player"
+                "path": "game.player2",
+                "account": "game"
               }
             ]
           }
         },
         {
-          "name": "player",
-          "signer": true
+          "name": "player1",
+          "writable": true
+        },
+        {
+          "name": "player2",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "referrals",
+          "accounts": [
+            {
+              "name": "player1Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            }
+          ]
         }
       ],
       "args": [
         {
-          "name": "newName",
-          "type": "string"
+          "name": "gameId",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "game",
-      "discriminator": [
-        27,
-        90,
-        166,
-        125,
-        74,
-        100,
-        121,
-        18
-      ]
-    },
-    {
-      "name": "globalState",
-      "discriminator": [
-        163,
-        46,
-        74,
-        168,
-        216,
-        123,
-        133,
-        98
-      ]
     },
     {
-      "name": "playerProfile",
+      "name": "claimFeeShare",
+      "docs": [
+        "Claim the caller's accrued share of SOL fees from the fee vault"
+      ],
       "discriminator": [
-        82,
-        226,
-        99,
-        87,
-        164,
-        130,
-        181,
-        80
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidGameState",
+        206,
+        6,
+        172,
+        166,
+        236,
+        234,
+        69,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipient",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "docs": [
+        "Claim all referral fees the caller's referred players have accrued"
+      ],
+      "discriminator": [
+        208,
+        216,
+        137,
+        78,
+        36,
+        103,
+        162,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referrer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrer",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "referralAccount"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimTimeoutVictory",
+      "docs": [
+        "Claim victory when opponent fails to reveal within timeout"
+      ],
+      "discriminator": [
+        21,
+        175,
+        209,
+        185,
+        79,
+        50,
+        147,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player1",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player2Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player2",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player1",
+          "writable": true
+        },
+        {
+          "name": "player2",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "referrals",
+          "accounts": [
+            {
+              "name": "player1Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeGame",
+      "docs": [
+        "Close a finished game and return its rent to player1, who paid for the account.",
+        "The outcome was already emitted as a GameSettled event when the game finished."
+      ],
+      "discriminator": [
+        237,
+        236,
+        157,
+        201,
+        253,
+        20,
+        248,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "player1",
+          "writable": true
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeJoinTicket",
+      "docs": [
+        "Close an unused join ticket (e.g. the game was cancelled or joined by someone else)"
+      ],
+      "discriminator": [
+        143,
+        36,
+        1,
+        0,
+        187,
+        197,
+        147,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "joinTicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  111,
+                  105,
+                  110,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closePlayerProfile",
+      "docs": [
+        "Close the caller's player profile and return its rent. Stats are lost; a later",
+        "create_game/join_game re-creates the profile from scratch via init_if_needed."
+      ],
+      "discriminator": [
+        136,
+        85,
+        182,
+        48,
+        212,
+        45,
+        129,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "commitMove",
+      "discriminator": [
+        27,
+        16,
+        69,
+        212,
+        175,
+        110,
+        123,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "moveHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createGame",
+      "discriminator": [
+        124,
+        69,
+        75,
+        66,
+        184,
+        220,
+        72,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "global_state.game_counter",
+                "account": "globalState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referral_account.referrer",
+                "account": "referralAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralLink",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  108,
+                  105,
+                  110,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "wagerTier",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  103,
+                  101,
+                  114,
+                  95,
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "wager_tier.tier_id",
+                "account": "wagerTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "playerTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "associatedTokenProgram",
+              "optional": true,
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            }
+          ]
+        },
+        {
+          "name": "playerMintStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "token"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "wager",
+          "type": "u64"
+        },
+        {
+          "name": "seriesLength",
+          "type": "u8"
+        },
+        {
+          "name": "invitedPlayer",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "joinSecretHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "maxFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createReferralAccount",
+      "docs": [
+        "Open the caller's referral account. Players can only sign up with a referrer that has one,",
+        "and their games' referral fees accrue to it."
+      ],
+      "discriminator": [
+        235,
+        55,
+        82,
+        230,
+        52,
+        35,
+        56,
+        210
+      ],
+      "accounts": [
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referrer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deposit",
+      "docs": [
+        "Move lamports from the player's wallet into their internal balance (available_funds).",
+        "Games can then be staked from the balance, and pay winnings back into it."
+      ],
+      "discriminator": [
+        242,
+        35,
+        198,
+        137,
+        82,
+        225,
+        242,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referral_account.referrer",
+                "account": "referralAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralLink",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  108,
+                  105,
+                  110,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeAdminChange",
+      "docs": [
+        "Apply a queued admin change once its delay has passed and, with a council, enough",
+        "members approved it (anyone can call this)"
+      ],
+      "discriminator": [
+        131,
+        163,
+        85,
+        59,
+        177,
+        128,
+        84,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "wagerTier",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  103,
+                  101,
+                  114,
+                  95,
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "wager_tier.tier_id",
+                "account": "wagerTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "changeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "changeId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initFeeVault",
+      "docs": [
+        "Create the fee vault for a deployment initialized before fees were vaulted",
+        "(only authority can call this). Run after migrate_global_state."
+      ],
+      "discriminator": [
+        141,
+        17,
+        88,
+        209,
+        137,
+        84,
+        89,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "globalState"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeCouncil",
+      "docs": [
+        "Hand admin control to an M-of-N admin council. From then on admin changes (including authority",
+        "transfers), fee withdrawals and new wager tiers need threshold approvals; any one member can",
+        "still pause (only authority can call this)"
+      ],
+      "discriminator": [
+        182,
+        245,
+        90,
+        36,
+        48,
+        240,
+        72,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "globalState"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeGlobalState",
+      "docs": [
+        "Initializes the global state - can only be called once due to #[account(init)],",
+        "and only by the program's upgrade authority"
+      ],
+      "discriminator": [
+        232,
+        254,
+        209,
+        244,
+        123,
+        89,
+        154,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programData",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  36,
+                  40,
+                  63,
+                  99,
+                  156,
+                  154,
+                  75,
+                  151,
+                  0,
+                  10,
+                  121,
+                  229,
+                  66,
+                  203,
+                  143,
+                  219,
+                  227,
+                  79,
+                  156,
+                  182,
+                  199,
+                  195,
+                  3,
+                  49,
+                  232,
+                  172,
+                  253,
+                  94,
+                  193,
+                  230,
+                  30,
+                  228
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                2,
+                168,
+                246,
+                145,
+                78,
+                136,
+                161,
+                176,
+                226,
+                16,
+                21,
+                62,
+                247,
+                99,
+                174,
+                43,
+                0,
+                194,
+                185,
+                61,
+                22,
+                193,
+                36,
+                210,
+                192,
+                83,
+                122,
+                16,
+                4,
+                128,
+                0,
+                0
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "feeCollector",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "joinGame",
+      "docs": [
+        "Join a waiting game. The game's locked wager and fee must match the terms the player signed for."
+      ],
+      "discriminator": [
+        107,
+        112,
+        18,
+        38,
+        56,
+        173,
+        60,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "player2Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referral_account.referrer",
+                "account": "referralAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralLink",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  108,
+                  105,
+                  110,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "joinTicket",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  111,
+                  105,
+                  110,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "playerTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "associatedTokenProgram",
+              "optional": true,
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            }
+          ]
+        },
+        {
+          "name": "playerMintStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "token"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "joinSecret",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "expectedWager",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateGame",
+      "docs": [
+        "Bring a game created by the first deployment up to the current layout (anyone can call this",
+        "and pays the rent for the grown account). Its wager becomes lamports and it gets the terms that",
+        "deployment played by: one round, net wagers refunded on ties, no no-reveal penalty. Games still",
+        "in play count toward the players' active games, so their profiles must be migrated first."
+      ],
+      "discriminator": [
+        166,
+        248,
+        193,
+        244,
+        30,
+        49,
+        33,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "player2Profile",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateGlobalState",
+      "docs": [
+        "Bring a global state created by an earlier deployment up to the current layout (only",
+        "authority can call this). The account is grown in place, the authority tops up its rent,",
+        "and the appended fields get the same defaults initialize_global_state uses."
+      ],
+      "discriminator": [
+        252,
+        251,
+        45,
+        153,
+        73,
+        1,
+        168,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePlayerProfile",
+      "docs": [
+        "Bring a player profile created by an earlier deployment up to the current layout (anyone can",
+        "call this and pays the rent for the grown account). The appended fields start empty."
+      ],
+      "discriminator": [
+        48,
+        116,
+        88,
+        39,
+        61,
+        247,
+        148,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openGameToPublic",
+      "docs": [
+        "Drop the invite and/or password on a private game so anyone can join (only player1, before anyone joined)"
+      ],
+      "discriminator": [
+        209,
+        116,
+        85,
+        122,
+        39,
+        157,
+        123,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "docs": [
+        "First step of an authority transfer: record the proposed new authority, which must then",
+        "call accept_authority itself (only authority can call this, and only until the council is",
+        "enabled; after that transfers go through an AdminChange::Authority change)"
+      ],
+      "discriminator": [
+        20,
+        148,
+        236,
+        198,
+        76,
+        119,
+        99,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "queueAdminChange",
+      "docs": [
+        "Queue an admin change. It can be executed by anyone once the admin change delay has passed",
+        "(and the council approved it), so players see it coming; call approvals are used by the call.",
+        "Only the authority can call this, or a council member once the council is enabled."
+      ],
+      "discriminator": [
+        62,
+        126,
+        207,
+        202,
+        249,
+        121,
+        175,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "wagerTier",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  103,
+                  101,
+                  114,
+                  95,
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "wager_tier.tier_id",
+                "account": "wagerTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "global_state.admin_change_counter",
+                "account": "globalState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "adminChange"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "requestJoin",
+      "docs": [
+        "First step to join a password-protected game: commit to hash(secret || player)",
+        "without revealing the secret. join_game must land in a later slot."
+      ],
+      "discriminator": [
+        70,
+        164,
+        111,
+        135,
+        228,
+        172,
+        97,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "joinTicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  111,
+                  105,
+                  110,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "requestRematch",
+      "docs": [
+        "Offer the opponent of a finished game a new game at the same wager.",
+        "The requester becomes player1 and escrows their wager; only the opponent can accept.",
+        "The fee is re-locked at current values, so it must not exceed the requester's bound."
+      ],
+      "discriminator": [
+        197,
+        249,
+        186,
+        24,
+        251,
+        45,
+        245,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "previousGame",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "previousGameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "global_state.game_counter",
+                "account": "globalState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referral_account.referrer",
+                "account": "referralAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referralLink",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  108,
+                  105,
+                  110,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "wagerTier",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  103,
+                  101,
+                  114,
+                  95,
+                  116,
+                  105,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "wager_tier.tier_id",
+                "account": "wagerTier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "playerTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "associatedTokenProgram",
+              "optional": true,
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            }
+          ]
+        },
+        {
+          "name": "playerMintStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "token"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "previousGameId",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revealMove",
+      "discriminator": [
+        30,
+        133,
+        198,
+        26,
+        106,
+        44,
+        55,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player1",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player2Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player2",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player1",
+          "writable": true
+        },
+        {
+          "name": "player2",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "referrals",
+          "accounts": [
+            {
+              "name": "player1Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "moveChoice",
+          "type": {
+            "defined": {
+              "name": "move"
+            }
+          }
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "docs": [
+        "Set the pause switches (only authority can call this; once the council is enabled any one",
+        "member can pause, and lifting a pause takes an AdminChange::Pause). Pausing new games stops",
+        "money coming in while games in play still settle; pausing everything also stops timeout",
+        "claims, cancels and withdrawals. Commits and reveals always go through, since their deadlines",
+        "keep running."
+      ],
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newGamesPaused",
+          "type": "bool"
+        },
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "settleExpiredReveal",
+      "docs": [
+        "Settle a game where neither player revealed before the deadline (callable by anyone).",
+        "Both players are refunded minus the no-reveal penalty locked at game creation."
+      ],
+      "discriminator": [
+        156,
+        225,
+        132,
+        228,
+        123,
+        29,
+        140,
+        17
+      ],
+      "accounts": [
+        {
+          "name": "game",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "caller",
+          "signer": true
+        },
+        {
+          "name": "player1Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player1",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player2Profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "game.player2",
+                "account": "game"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player1",
+          "writable": true
+        },
+        {
+          "name": "player2",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "mint",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player1TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "player2TokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeVaultTokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "optional": true
+            },
+            {
+              "name": "player1MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2MintStats",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      108,
+                      97,
+                      121,
+                      101,
+                      114,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.player",
+                    "account": "playerMintStats"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_mint_stats.mint",
+                    "account": "playerMintStats"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "referrals",
+          "accounts": [
+            {
+              "name": "player1Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player1_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "player2Referral",
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "player2_referral.referrer",
+                    "account": "referralAccount"
+                  }
+                ]
+              }
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updatePlayerName",
+      "docs": [
+        "Update player name (can be called anytime)"
+      ],
+      "discriminator": [
+        1,
+        189,
+        54,
+        68,
+        216,
+        13,
+        185,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "docs": [
+        "Move lamports from the player's internal balance back to their wallet"
+      ],
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "playerProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fundsVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  117,
+                  110,
+                  100,
+                  115,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "Withdraw accrued SOL fees from the fee vault to the fee collector (only authority can call",
+        "this, or the council through an approved AdminChange::WithdrawFees)"
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "approvedChange",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "approved_change.change_id",
+                "account": "pendingAdminChange"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "feeCollector",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawTokenFees",
+      "docs": [
+        "Withdraw SPL fees held in the fee vault's token account (only authority can call this, or the",
+        "council through an approved AdminChange::WithdrawTokenFees).",
+        "The fee split is paid out of the withdrawn amount: remaining accounts are each fee share",
+        "recipient's ATA for the mint, in fee_shares order; the rest goes to the fee collector.",
+        "Token fees are not part of the lamport fee totals."
+      ],
+      "discriminator": [
+        148,
+        11,
+        90,
+        7,
+        99,
+        98,
+        153,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "globalState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "council",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  99,
+                  105,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "approvedChange",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "approved_change.change_id",
+                "account": "pendingAdminChange"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeVault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeCollectorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminCouncil",
+      "discriminator": [
+        34,
+        131,
+        90,
+        39,
+        225,
+        74,
+        125,
+        139
+      ]
+    },
+    {
+      "name": "feeVault",
+      "discriminator": [
+        192,
+        178,
+        69,
+        232,
+        58,
+        149,
+        157,
+        132
+      ]
+    },
+    {
+      "name": "fundsVault",
+      "discriminator": [
+        107,
+        138,
+        229,
+        107,
+        91,
+        88,
+        229,
+        109
+      ]
+    },
+    {
+      "name": "game",
+      "discriminator": [
+        27,
+        90,
+        166,
+        125,
+        74,
+        100,
+        121,
+        18
+      ]
+    },
+    {
+      "name": "globalState",
+      "discriminator": [
+        163,
+        46,
+        74,
+        168,
+        216,
+        123,
+        133,
+        98
+      ]
+    },
+    {
+      "name": "joinTicket",
+      "discriminator": [
+        235,
+        134,
+        253,
+        22,
+        43,
+        47,
+        187,
+        18
+      ]
+    },
+    {
+      "name": "pendingAdminChange",
+      "discriminator": [
+        201,
+        252,
+        8,
+        211,
+        154,
+        215,
+        133,
+        73
+      ]
+    },
+    {
+      "name": "playerMintStats",
+      "discriminator": [
+        184,
+        194,
+        31,
+        24,
+        69,
+        214,
+        133,
+        90
+      ]
+    },
+    {
+      "name": "playerProfile",
+      "discriminator": [
+        82,
+        226,
+        99,
+        87,
+        164,
+        130,
+        181,
+        80
+      ]
+    },
+    {
+      "name": "referralAccount",
+      "discriminator": [
+        237,
+        162,
+        80,
+        78,
+        196,
+        233,
+        91,
+        2
+      ]
+    },
+    {
+      "name": "referralLink",
+      "discriminator": [
+        30,
+        231,
+        159,
+        98,
+        189,
+        47,
+        48,
+        5
+      ]
+    },
+    {
+      "name": "wagerTier",
+      "discriminator": [
+        173,
+        3,
+        175,
+        43,
+        118,
+        219,
+        183,
+        119
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adminChangeApproved",
+      "discriminator": [
+        240,
+        81,
+        151,
+        72,
+        144,
+        250,
+        191,
+        162
+      ]
+    },
+    {
+      "name": "adminChangeCancelled",
+      "discriminator": [
+        188,
+        179,
+        107,
+        14,
+        160,
+        252,
+        35,
+        190
+      ]
+    },
+    {
+      "name": "adminChangeExecuted",
+      "discriminator": [
+        59,
+        12,
+        172,
+        132,
+        81,
+        46,
+        205,
+        36
+      ]
+    },
+    {
+      "name": "adminChangeQueued",
+      "discriminator": [
+        219,
+        155,
+        168,
+        249,
+        28,
+        178,
+        216,
+        176
+      ]
+    },
+    {
+      "name": "authorityTransferCancelled",
+      "discriminator": [
+        31,
+        228,
+        187,
+        148,
+        20,
+        99,
+        237,
+        48
+      ]
+    },
+    {
+      "name": "authorityTransferProposed",
+      "discriminator": [
+        103,
+        244,
+        27,
+        116,
+        177,
+        4,
+        100,
+        119
+      ]
+    },
+    {
+      "name": "authorityTransferred",
+      "discriminator": [
+        245,
+        109,
+        179,
+        54,
+        135,
+        92,
+        22,
+        64
+      ]
+    },
+    {
+      "name": "councilUpdated",
+      "discriminator": [
+        182,
+        249,
+        88,
+        104,
+        17,
+        46,
+        214,
+        21
+      ]
+    },
+    {
+      "name": "feesWithdrawn",
+      "discriminator": [
+        234,
+        15,
+        0,
+        119,
+        148,
+        241,
+        40,
+        21
+      ]
+    },
+    {
+      "name": "fundsDeposited",
+      "discriminator": [
+        157,
+        209,
+        100,
+        95,
+        59,
+        100,
+        3,
+        68
+      ]
+    },
+    {
+      "name": "fundsWithdrawn",
+      "discriminator": [
+        56,
+        130,
+        230,
+        154,
+        35,
+        92,
+        11,
+        118
+      ]
+    },
+    {
+      "name": "gameCancelled",
+      "discriminator": [
+        113,
+        20,
+        200,
+        104,
+        76,
+        35,
+        9,
+        241
+      ]
+    },
+    {
+      "name": "gameClosed",
+      "discriminator": [
+        178,
+        203,
+        179,
+        224,
+        43,
+        18,
+        209,
+        4
+      ]
+    },
+    {
+      "name": "gameSettled",
+      "discriminator": [
+        63,
+        109,
+        128,
+        85,
+        229,
+        63,
+        167,
+        176
+      ]
+    },
+    {
+      "name": "pauseUpdated",
+      "discriminator": [
+        203,
+        203,
+        33,
+        225,
+        130,
+        103,
+        90,
+        105
+      ]
+    },
+    {
+      "name": "playerProfileClosed",
+      "discriminator": [
+        78,
+        156,
+        194,
+        30,
+        113,
+        60,
+        143,
+        1
+      ]
+    },
+    {
+      "name": "referralFeesClaimed",
+      "discriminator": [
+        118,
+        130,
+        122,
+        41,
+        74,
+        34,
+        240,
+        48
+      ]
+    },
+    {
+      "name": "tokenPayout",
+      "discriminator": [
+        82,
+        105,
+        34,
+        230,
+        126,
+        66,
+        100,
+        33
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidGameState",
       "msg": "Invalid game state"
     },
     {
-      "code": 6001,
-      "name": "cannotJoinOwnGame",
-      "msg": "Cannot join your own game"
+      "code": 6001,
+      "name": "cannotJoinOwnGame",
+      "msg": "Cannot join your own game"
+    },
+    {
+      "code": 6002,
+      "name": "notPlayerInGame",
+      "msg": "Not a player in this game"
+    },
+    {
+      "code": 6003,
+      "name": "alreadyCommitted",
+      "msg": "Already committed move"
+    },
+    {
+      "code": 6004,
+      "name": "alreadyRevealed",
+      "msg": "Already revealed move"
+    },
+    {
+      "code": 6005,
+      "name": "invalidReveal",
+      "msg": "Invalid reveal - hash doesn't match"
+    },
+    {
+      "code": 6006,
+      "name": "globalStateNotInitialized",
+      "msg": "Global state not initialized"
+    },
+    {
+      "code": 6007,
+      "name": "unauthorized",
+      "msg": "Only authority can perform this action"
+    },
+    {
+      "code": 6008,
+      "name": "noDeadlineSet",
+      "msg": "No deadline has been set for this game"
+    },
+    {
+      "code": 6009,
+      "name": "deadlineNotReached",
+      "msg": "Reveal deadline has not been reached yet"
+    },
+    {
+      "code": 6010,
+      "name": "claimerDidNotReveal",
+      "msg": "Claimer has not revealed their move"
+    },
+    {
+      "code": 6011,
+      "name": "opponentAlreadyRevealed",
+      "msg": "Opponent has already revealed their move"
+    },
+    {
+      "code": 6012,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds in game account for payout"
+    },
+    {
+      "code": 6013,
+      "name": "notGameCreator",
+      "msg": "Only the game creator can perform this action"
+    },
+    {
+      "code": 6014,
+      "name": "commitDeadlineNotReached",
+      "msg": "Commit deadline has not been reached yet"
+    },
+    {
+      "code": 6015,
+      "name": "claimerDidNotCommit",
+      "msg": "Claimer has not committed their move"
+    },
+    {
+      "code": 6016,
+      "name": "commitDeadlinePassed",
+      "msg": "Commit deadline has passed"
+    },
+    {
+      "code": 6017,
+      "name": "revealDeadlinePassed",
+      "msg": "Reveal deadline has passed"
+    },
+    {
+      "code": 6018,
+      "name": "invalidPenaltyPercentage",
+      "msg": "Penalty percentage must be between 0 and 100"
+    },
+    {
+      "code": 6019,
+      "name": "gameNotSettled",
+      "msg": "Game has no recorded settlement"
+    },
+    {
+      "code": 6020,
+      "name": "playerHasActiveGames",
+      "msg": "Player still has unfinished games"
+    },
+    {
+      "code": 6021,
+      "name": "notInvitedPlayer",
+      "msg": "This game is reserved for another player"
+    },
+    {
+      "code": 6022,
+      "name": "inviteExpired",
+      "msg": "The invite for this game has expired"
+    },
+    {
+      "code": 6023,
+      "name": "gameNotPrivate",
+      "msg": "Game is already open to the public"
+    },
+    {
+      "code": 6024,
+      "name": "invalidJoinSecret",
+      "msg": "Invalid join secret"
+    },
+    {
+      "code": 6025,
+      "name": "joinTicketRequired",
+      "msg": "A join ticket is required for password-protected games"
+    },
+    {
+      "code": 6026,
+      "name": "joinTicketTooRecent",
+      "msg": "Join ticket must be requested in an earlier slot"
+    },
+    {
+      "code": 6027,
+      "name": "rematchAlreadyRequested",
+      "msg": "A rematch was already requested for this game"
+    },
+    {
+      "code": 6028,
+      "name": "notARematch",
+      "msg": "Game is not a rematch"
+    },
+    {
+      "code": 6029,
+      "name": "invalidSeriesLength",
+      "msg": "Series length must be 1, 3, 5 or 7"
+    },
+    {
+      "code": 6030,
+      "name": "wagerOutOfRange",
+      "msg": "Wager is outside the allowed range"
+    },
+    {
+      "code": 6031,
+      "name": "invalidWagerLimits",
+      "msg": "Minimum wager must be positive and not above the maximum"
+    },
+    {
+      "code": 6032,
+      "name": "invalidWagerTier",
+      "msg": "Wager tier needs a positive wager"
+    },
+    {
+      "code": 6033,
+      "name": "wagerTierDisabled",
+      "msg": "Wager tier is disabled"
+    },
+    {
+      "code": 6034,
+      "name": "wagerTierMismatch",
+      "msg": "Wager does not match the wager tier"
+    },
+    {
+      "code": 6035,
+      "name": "tokenAccountsRequired",
+      "msg": "Token accounts are required for SPL token games"
+    },
+    {
+      "code": 6036,
+      "name": "invalidTokenAccount",
+      "msg": "Token account does not match the game's mint or owner"
+    },
+    {
+      "code": 6037,
+      "name": "unsupportedTokenProgram",
+      "msg": "Unsupported token program"
+    },
+    {
+      "code": 6038,
+      "name": "unsupportedMintExtension",
+      "msg": "Token-2022 mint uses an unsupported extension"
+    },
+    {
+      "code": 6039,
+      "name": "invalidTransferFee",
+      "msg": "Token transfer fee could not be covered"
+    },
+    {
+      "code": 6040,
+      "name": "invalidFundsAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6041,
+      "name": "insufficientAvailableFunds",
+      "msg": "Not enough available funds"
+    },
+    {
+      "code": 6042,
+      "name": "fundsVaultRequired",
+      "msg": "Funds vault account is required"
+    },
+    {
+      "code": 6043,
+      "name": "internalBalanceSolOnly",
+      "msg": "Available funds can only stake SOL wagers"
+    },
+    {
+      "code": 6044,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6045,
+      "name": "feeExceedsWager",
+      "msg": "Fee would take the whole wager"
+    },
+    {
+      "code": 6046,
+      "name": "feeAboveCap",
+      "msg": "Fee is above the fee cap"
+    },
+    {
+      "code": 6047,
+      "name": "invalidFeeLimits",
+      "msg": "Fee cap must be below 100% and not below the current fee, and the minimum fee not above the maximum"
+    },
+    {
+      "code": 6048,
+      "name": "insufficientFeeBalance",
+      "msg": "Not enough fees in the fee vault"
+    },
+    {
+      "code": 6049,
+      "name": "invalidFeeSplit",
+      "msg": "Fee split needs distinct recipients with positive shares totalling at most 100%"
+    },
+    {
+      "code": 6050,
+      "name": "feeShareNotClaimed",
+      "msg": "A removed fee split recipient still has fees to claim"
+    },
+    {
+      "code": 6051,
+      "name": "notFeeShareRecipient",
+      "msg": "Not a fee split recipient"
+    },
+    {
+      "code": 6052,
+      "name": "invalidReferralShare",
+      "msg": "Referral share cannot exceed 100%"
+    },
+    {
+      "code": 6053,
+      "name": "selfReferral",
+      "msg": "Players cannot refer themselves"
+    },
+    {
+      "code": 6054,
+      "name": "notReferrer",
+      "msg": "Not the referrer of this player"
+    },
+    {
+      "code": 6055,
+      "name": "feeAboveMaximum",
+      "msg": "Fee is higher than the maximum the player accepted"
+    },
+    {
+      "code": 6056,
+      "name": "unexpectedWager",
+      "msg": "Game wager differs from the expected wager"
+    },
+    {
+      "code": 6057,
+      "name": "invalidAuthorityTransfer",
+      "msg": "New authority must differ from the current authority"
+    },
+    {
+      "code": 6058,
+      "name": "noPendingAuthority",
+      "msg": "No authority transfer is pending"
+    },
+    {
+      "code": 6059,
+      "name": "notPendingAuthority",
+      "msg": "Only the proposed authority can accept the transfer"
+    },
+    {
+      "code": 6060,
+      "name": "newGamesPaused",
+      "msg": "New games and deposits are paused"
+    },
+    {
+      "code": 6061,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6062,
+      "name": "adminChangeNotReady",
+      "msg": "Admin change delay has not passed yet"
+    },
+    {
+      "code": 6063,
+      "name": "invalidAdminChangeDelay",
+      "msg": "Admin change delay must be between 0 and 30 days"
+    },
+    {
+      "code": 6064,
+      "name": "invalidCouncil",
+      "msg": "Council needs distinct members (at most 10) and a threshold between 1 and the member count"
+    },
+    {
+      "code": 6065,
+      "name": "councilRequired",
+      "msg": "Admin council account is required"
+    },
+    {
+      "code": 6066,
+      "name": "notCouncilMember",
+      "msg": "Not an admin council member"
+    },
+    {
+      "code": 6067,
+      "name": "alreadyApproved",
+      "msg": "Member already approved this admin change"
+    },
+    {
+      "code": 6068,
+      "name": "notEnoughApprovals",
+      "msg": "Admin change doesn't have enough council approvals"
+    },
+    {
+      "code": 6069,
+      "name": "previousGameRequired",
+      "msg": "Cancelling a rematch needs the game it was requested from"
+    },
+    {
+      "code": 6070,
+      "name": "mintStatsRequired",
+      "msg": "SPL games need the players' stats accounts for the game's mint"
+    },
+    {
+      "code": 6071,
+      "name": "feeShareAccountsRequired",
+      "msg": "Pass each fee share recipient's token account, in fee split order"
+    },
+    {
+      "code": 6072,
+      "name": "referralAccountRequired",
+      "msg": "The player's referrer's referral account is required"
+    },
+    {
+      "code": 6073,
+      "name": "referralLinkRequired",
+      "msg": "Creating a player profile needs the player's referral link account"
+    },
+    {
+      "code": 6074,
+      "name": "referrerAlreadySet",
+      "msg": "This player already has a different referrer"
+    },
+    {
+      "code": 6075,
+      "name": "duplicateReferralAccount",
+      "msg": "Pass a shared referrer's account once, as player1's"
+    },
+    {
+      "code": 6076,
+      "name": "alreadyMigrated",
+      "msg": "Global state is already on the current layout"
+    },
+    {
+      "code": 6077,
+      "name": "wagerTierRequired",
+      "msg": "This admin change needs the wager tier it updates"
+    },
+    {
+      "code": 6078,
+      "name": "notProposer",
+      "msg": "Only the council member who queued this admin change can cancel it"
+    },
+    {
+      "code": 6079,
+      "name": "councilGoverned",
+      "msg": "The admin council governs this: queue an admin change instead"
+    },
+    {
+      "code": 6080,
+      "name": "approvedChangeRequired",
+      "msg": "Once the council is enabled this call needs an approved admin change"
+    },
+    {
+      "code": 6081,
+      "name": "approvedChangeMismatch",
+      "msg": "The approved admin change is for a different call"
+    },
+    {
+      "code": 6082,
+      "name": "approvedCallNotExecutable",
+      "msg": "This admin change approves a single call and is used up by that call"
+    },
+    {
+      "code": 6083,
+      "name": "proposerRequired",
+      "msg": "Pass the member who queued the approved admin change, who gets its rent back"
+    },
+    {
+      "code": 6084,
+      "name": "profileNotMigrated",
+      "msg": "Player profile is still in the deployed layout: call migrate_player_profile first"
+    },
+    {
+      "code": 6085,
+      "name": "playerProfileRequired",
+      "msg": "Migrating a game in play needs its players' profiles"
+    }
+  ],
+  "types": [
+    {
+      "name": "adminChange",
+      "docs": [
+        "Parameter changes and admin call approvals that only go through the admin change timelock"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "feeBps",
+            "fields": [
+              {
+                "name": "feeBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "feeLimits",
+            "fields": [
+              {
+                "name": "maxFeeBps",
+                "type": "u16"
+              },
+              {
+                "name": "minFee",
+                "type": "u64"
+              },
+              {
+                "name": "maxFee",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "feeCollector",
+            "fields": [
+              {
+                "name": "feeCollector",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "feeSplit",
+            "fields": [
+              {
+                "name": "entries",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "feeSplitEntry"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "referralBps",
+            "fields": [
+              {
+                "name": "referralBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "changeDelay",
+            "fields": [
+              {
+                "name": "delaySeconds",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "wagerTier",
+            "fields": [
+              {
+                "name": "tierId",
+                "type": "u32"
+              },
+              {
+                "name": "wager",
+                "type": "u64"
+              },
+              {
+                "name": "feeBps",
+                "type": "u16"
+              },
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "noRevealPenalty",
+            "fields": [
+              {
+                "name": "percentage",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "council",
+            "fields": [
+              {
+                "name": "members",
+                "type": {
+                  "vec": "pubkey"
+                }
+              },
+              {
+                "name": "threshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "authority",
+            "fields": [
+              {
+                "name": "newAuthority",
+                "type": {
+                  "option": "pubkey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "wagerLimits",
+            "fields": [
+              {
+                "name": "minWager",
+                "type": "u64"
+              },
+              {
+                "name": "maxWager",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "tiePolicy",
+            "fields": [
+              {
+                "name": "tiePolicy",
+                "type": {
+                  "defined": {
+                    "name": "tiePolicy"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "pause",
+            "fields": [
+              {
+                "name": "newGamesPaused",
+                "type": "bool"
+              },
+              {
+                "name": "paused",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "withdrawFees",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "withdrawTokenFees",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "addWagerTier",
+            "fields": [
+              {
+                "name": "wager",
+                "type": "u64"
+              },
+              {
+                "name": "feeBps",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminChangeApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "changeId",
+            "type": "u64"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "notPlayerInGame",
-      "msg": "Not a player in this game"
+      "name": "adminChangeCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "changeId",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "adminChange"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminChangeExecuted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "changeId",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "adminChange"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminChangeQueued",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "changeId",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "adminChange"
+              }
+            }
+          },
+          {
+            "name": "effectiveAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminCouncil",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityTransferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityTransferProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          },
+          {
+            "name": "claimable",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "alreadyCommitted",
-      "msg": "Already committed move"
+      "name": "feeSplitEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "alreadyRevealed",
-      "msg": "Already revealed move"
+      "name": "feeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "invalidReveal",
-      "msg": "Invalid reveal - hash doesn't match"
+      "name": "feesWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "globalStateNotInitialized",
-      "msg": "Global state not initialized"
+      "name": "fundsDeposited",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "availableFunds",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "unauthorized",
-      "msg": "Only authority can perform this action"
+      "name": "fundsVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "noDeadlineSet",
-      "msg": "No deadline has been set for this game"
+      "name": "fundsWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "availableFunds",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "deadlineNotReached",
-      "msg": "Reveal deadline has not been reached yet"
+      "name": "game",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "player1",
+            "type": "pubkey"
+          },
+          {
+            "name": "player2",
+            "type": "pubkey"
+          },
+          {
+            "name": "wager",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "player1UsesFunds",
+            "type": "bool"
+          },
+          {
+            "name": "player2UsesFunds",
+            "type": "bool"
+          },
+          {
+            "name": "wagerTier",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "feePerPlayer",
+            "type": "u64"
+          },
+          {
+            "name": "seriesLength",
+            "type": "u8"
+          },
+          {
+            "name": "roundResults",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "winner"
+                }
+              }
+            }
+          },
+          {
+            "name": "roundTies",
+            "type": "u8"
+          },
+          {
+            "name": "noRevealPenalty",
+            "type": "u64"
+          },
+          {
+            "name": "tiePolicy",
+            "type": {
+              "defined": {
+                "name": "tiePolicy"
+              }
+            }
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": {
+                "name": "gameState"
+              }
+            }
+          },
+          {
+            "name": "player1MoveHash",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "player2MoveHash",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "player1Move",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "move"
+                }
+              }
+            }
+          },
+          {
+            "name": "player2Move",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "move"
+                }
+              }
+            }
+          },
+          {
+            "name": "winnerType",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "winner"
+                }
+              }
+            }
+          },
+          {
+            "name": "winnerAddress",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "commitDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "revealDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "invitedPlayer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "inviteExpiry",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "joinSecretHash",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "previousGameId",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rematchGameId",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "player1TokenSurplus",
+            "type": "u64"
+          },
+          {
+            "name": "player2TokenSurplus",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "claimerDidNotReveal",
-      "msg": "Claimer has not revealed their move"
+      "name": "gameCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "player1",
+            "type": "pubkey"
+          },
+          {
+            "name": "wager",
+            "type": "u64"
+          },
+          {
+            "name": "refunded",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "opponentAlreadyRevealed",
-      "msg": "Opponent has already revealed their move"
+      "name": "gameClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "rentReceiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds in game account for payout"
-    }
-  ],
-  "types": [
-    {
-      "name": "game",
+      "name": "gameSettled",
       "type": {
         "kind": "struct",
         "fields": [
@@ -985,10 +7710,12 @@ export type CryptoPvp = {
           },
           {
             "name": "wager",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
             "type": {
-              "defined": {
-                "name": "wagerAmount"
-              }
+              "option": "pubkey"
             }
           },
           {
@@ -996,76 +7723,233 @@ export type CryptoPvp = {
             "type": "u64"
           },
           {
-            "name": "state",
+            "name": "player1Move",
             "type": {
-              "defined": {
-                "name": "gameState"
+              "option": {
+                "defined": {
+                  "name": "move"
+                }
               }
             }
           },
           {
-            "name": "player1MoveHash",
+            "name": "player2Move",
             "type": {
               "option": {
-                "array": [
-                  "u8",
-                  32
-                ]
+                "defined": {
+                  "name": "move"
+                }
               }
             }
           },
           {
-            "name": "player2MoveHash",
+            "name": "winnerType",
             "type": {
-              "option": {
-                "array": [
-                  "u8",
-                  32
-                ]
+              "defined": {
+                "name": "winner"
               }
             }
           },
           {
-            "name": "player1Move",
+            "name": "winnerAddress",
             "type": {
-              "option": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "seriesLength",
+            "type": "u8"
+          },
+          {
+            "name": "roundResults",
+            "type": {
+              "vec": {
                 "defined": {
-                  "name": "move"
+                  "name": "winner"
+                }
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "waitingForPlayer"
+          },
+          {
+            "name": "commitPhase"
+          },
+          {
+            "name": "revealPhase"
+          },
+          {
+            "name": "finished"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameCounter",
+            "type": "u64"
+          },
+          {
+            "name": "totalGamesCompleted",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeCollector",
+            "type": "pubkey"
+          },
+          {
+            "name": "legacyFeePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "newGamesPaused",
+            "type": "bool"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxFee",
+            "type": "u64"
+          },
+          {
+            "name": "minWager",
+            "type": "u64"
+          },
+          {
+            "name": "maxWager",
+            "type": "u64"
+          },
+          {
+            "name": "wagerTierCounter",
+            "type": "u32"
+          },
+          {
+            "name": "noRevealPenaltyPercentage",
+            "type": "u64"
+          },
+          {
+            "name": "tiePolicy",
+            "type": {
+              "defined": {
+                "name": "tiePolicy"
+              }
+            }
+          },
+          {
+            "name": "totalFeesAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "feeShares",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeShare"
                 }
               }
             }
           },
           {
-            "name": "player2Move",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "move"
-                }
-              }
-            }
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "unclaimedReferralFees",
+            "type": "u64"
+          },
+          {
+            "name": "adminChangeDelay",
+            "type": "i64"
+          },
+          {
+            "name": "adminChangeCounter",
+            "type": "u64"
+          },
+          {
+            "name": "councilEnabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "joinTicket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
           },
           {
-            "name": "winnerType",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "winner"
-                }
-              }
-            }
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "winnerAddress",
+            "name": "commitment",
             "type": {
-              "option": "pubkey"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "revealDeadline",
-            "type": {
-              "option": "i64"
-            }
+            "name": "slot",
+            "type": "u64"
           },
           {
             "name": "bump",
@@ -1075,49 +7959,76 @@ export type CryptoPvp = {
       }
     },
     {
-      "name": "gameState",
+      "name": "move",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "waitingForPlayer"
+            "name": "rock"
           },
           {
-            "name": "commitPhase"
+            "name": "paper"
           },
           {
-            "name": "revealPhase"
+            "name": "scissors"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newGamesPaused",
+            "type": "bool"
           },
           {
-            "name": "finished"
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "globalState",
+      "name": "pendingAdminChange",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "gameCounter",
+            "name": "changeId",
             "type": "u64"
           },
           {
-            "name": "totalGamesCompleted",
-            "type": "u64"
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "adminChange"
+              }
+            }
           },
           {
-            "name": "authority",
+            "name": "proposedBy",
             "type": "pubkey"
           },
           {
-            "name": "feeCollector",
-            "type": "pubkey"
+            "name": "effectiveAt",
+            "type": "i64"
           },
           {
-            "name": "feePercentage",
-            "type": "u64"
+            "name": "approvals",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
             "name": "bump",
@@ -1127,18 +8038,36 @@ export type CryptoPvp = {
       }
     },
     {
-      "name": "move",
+      "name": "playerMintStats",
+      "docs": [
+        "A player's lifetime stats in one SPL mint, in that mint's raw token units"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "rock"
+            "name": "player",
+            "type": "pubkey"
           },
           {
-            "name": "paper"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "scissors"
+            "name": "totalWagered",
+            "type": "u64"
+          },
+          {
+            "name": "totalWon",
+            "type": "u64"
+          },
+          {
+            "name": "totalLost",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
@@ -1208,6 +8137,127 @@ export type CryptoPvp = {
             "name": "createdAt",
             "type": "i64"
           },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "totalGamesCancelled",
+            "type": "u64"
+          },
+          {
+            "name": "activeGames",
+            "type": "u32"
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerProfileClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedFunds",
+            "type": "u64"
+          },
+          {
+            "name": "totalGamesPlayed",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralAccount",
+      "docs": [
+        "A referrer's referral stats and the SOL fees their referred players have accrued to them.",
+        "Never closed, so unclaimed fees survive the referred players closing their profiles."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "referralCount",
+            "type": "u32"
+          },
+          {
+            "name": "claimable",
+            "type": "u64"
+          },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralFeesClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "referralEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralLink",
+      "docs": [
+        "Who referred a player. Never closed, so a closed and re-created profile keeps its referrer."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
           {
             "name": "bump",
             "type": "u8"
@@ -1216,18 +8266,78 @@ export type CryptoPvp = {
       }
     },
     {
-      "name": "wagerAmount",
+      "name": "tiePolicy",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "sol1"
+            "name": "refund"
+          },
+          {
+            "name": "replay"
+          },
+          {
+            "name": "refundWithFees"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenPayout",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          },
+          {
+            "name": "netAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "wagerTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tierId",
+            "type": "u32"
+          },
+          {
+            "name": "wager",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
           },
           {
-            "name": "sol01"
+            "name": "enabled",
+            "type": "bool"
           },
           {
-            "name": "sol001"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
@@ -1251,6 +8361,18 @@ export type CryptoPvp = {
           },
           {
             "name": "player2OpponentForfeit"
+          },
+          {
+            "name": "player1OpponentNoCommit"
+          },
+          {
+            "name": "player2OpponentNoCommit"
+          },
+          {
+            "name": "noCommitRefund"
+          },
+          {
+            "name": "noRevealRefund"
           }
         ]
       }
@@ -2,7 +2,7 @@ import { Connection, PublicKey } from '@solana/web3.js';
 import { Program, AnchorProvider } from '@coral-xyz/anchor';
 import { WalletContextState } from '@solana/wallet-adapter-react';
 import idl from '../idl/crypto_pvp.json';
-import { Game, WagerAmount } from '../App';
+import { Game, wagerFromLamports } from '../App';
 
 const PROGRAM_ID = new PublicKey(idl.address);
 
@@ -90,12 +90,9 @@ export const fetchAvailableGames = async (
           continue;
         }
 
-        // Convert wager enum to our type
-        let wager: WagerAmount = 'sol01';
-        
-        if (gameData.wager?.sol1) wager = 'sol1';
-        else if (gameData.wager?.sol01) wager = 'sol01';
-        else if (gameData.wager?.sol001) wager = 'sol001';
+        // The app only plays SOL games; wagers outside the presets are shown in lamports
+        if (gameData.mint) continue;
+        const wager = wagerFromLamports(gameData.wager.toNumber()) ?? gameData.wager.toNumber();
 
         // Fetch player1 name (fallback to truncated address if no wallet)
         const player1Name = wallet 
@@ -112,6 +109,7 @@ export const fetchAvailableGames = async (
           player1Name,
           player2: cleanPlayer2,
           wager,
+          feePerPlayer: gameData.feePerPlayer.toNumber(),
           status: 'WaitingForPlayer',
           createdAt: new Date(), // You could add timestamp to your program
         };
@@ -197,11 +195,9 @@ export const fetchMyActiveGames = async (
         // Skip finished games
         if (status === 'Finished') continue;
 
-        // Convert wager enum to our type
-        let wager: WagerAmount = 'sol01';
-        if (gameData.wager?.sol1) wager = 'sol1';
-        else if (gameData.wager?.sol01) wager = 'sol01';
-        else if (gameData.wager?.sol001) wager = 'sol001';
+        // The app only plays SOL games; wagers outside the presets are shown in lamports
+        if (gameData.mint) continue;
+        const wager = wagerFromLamports(gameData.wager.toNumber()) ?? gameData.wager.toNumber();
 
         // Fetch player names
         const player1Name = await fetchPlayerName(connection, wallet, gameData.player1.toString());
@@ -218,6 +214,7 @@ export const fetchMyActiveGames = async (
           player2: isDefaultPlayer2 ? undefined : rawPlayer2, // Set to undefined if it's the default/empty address
           player2Name,
           wager,
+          feePerPlayer: gameData.feePerPlayer.toNumber(),
           status,
           // Add blockchain move data for proper status tracking  
           player1Move: (gameData.player1Move || gameData.player1_move) ? 
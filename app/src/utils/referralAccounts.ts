@@ -0,0 +1,28 @@
+import { PublicKey } from '@solana/web3.js';
+import { Program } from '@coral-xyz/anchor';
+import idl from '../idl/crypto_pvp.json';
+
+const PROGRAM_ID = new PublicKey(idl.address);
+
+// New profiles need the player's referral link; once it records a referrer,
+// the player's fees accrue to that referrer's referral account
+export const fetchReferralAccounts = async (
+  program: Program,
+  player: PublicKey
+): Promise<{ referralLink: PublicKey; referralAccount: PublicKey | null }> => {
+  const [referralLink] = await PublicKey.findProgramAddress([
+    Buffer.from('referral_link'),
+    player.toBytes()
+  ], PROGRAM_ID);
+
+  const link = await (program.account as any).referralLink.fetchNullable(referralLink);
+  if (!link || link.player.equals(PublicKey.default)) {
+    return { referralLink, referralAccount: null }; // No referrer
+  }
+
+  const [referralAccount] = await PublicKey.findProgramAddress([
+    Buffer.from('referral'),
+    link.referrer.toBytes()
+  ], PROGRAM_ID);
+  return { referralLink, referralAccount };
+};
@@ -28,4 +28,150 @@ pub enum GameError {
     OpponentAlreadyRevealed,
     #[msg("Insufficient funds in game account for payout")]
     InsufficientFunds,
+    #[msg("Only the game creator can perform this action")]
+    NotGameCreator,
+    #[msg("Commit deadline has not been reached yet")]
+    CommitDeadlineNotReached,
+    #[msg("Claimer has not committed their move")]
+    ClaimerDidNotCommit,
+    #[msg("Commit deadline has passed")]
+    CommitDeadlinePassed,
+    #[msg("Reveal deadline has passed")]
+    RevealDeadlinePassed,
+    #[msg("Penalty percentage must be between 0 and 100")]
+    InvalidPenaltyPercentage,
+    #[msg("Game has no recorded settlement")]
+    GameNotSettled,
+    #[msg("Player still has unfinished games")]
+    PlayerHasActiveGames,
+    #[msg("This game is reserved for another player")]
+    NotInvitedPlayer,
+    #[msg("The invite for this game has expired")]
+    InviteExpired,
+    #[msg("Game is already open to the public")]
+    GameNotPrivate,
+    #[msg("Invalid join secret")]
+    InvalidJoinSecret,
+    #[msg("A join ticket is required for password-protected games")]
+    JoinTicketRequired,
+    #[msg("Join ticket must be requested in an earlier slot")]
+    JoinTicketTooRecent,
+    #[msg("A rematch was already requested for this game")]
+    RematchAlreadyRequested,
+    #[msg("Game is not a rematch")]
+    NotARematch,
+    #[msg("Series length must be 1, 3, 5 or 7")]
+    InvalidSeriesLength,
+    #[msg("Wager is outside the allowed range")]
+    WagerOutOfRange,
+    #[msg("Minimum wager must be positive and not above the maximum")]
+    InvalidWagerLimits,
+    #[msg("Wager tier needs a positive wager")]
+    InvalidWagerTier,
+    #[msg("Wager tier is disabled")]
+    WagerTierDisabled,
+    #[msg("Wager does not match the wager tier")]
+    WagerTierMismatch,
+    #[msg("Token accounts are required for SPL token games")]
+    TokenAccountsRequired,
+    #[msg("Token account does not match the game's mint or owner")]
+    InvalidTokenAccount,
+    #[msg("Unsupported token program")]
+    UnsupportedTokenProgram,
+    #[msg("Token-2022 mint uses an unsupported extension")]
+    UnsupportedMintExtension,
+    #[msg("Token transfer fee could not be covered")]
+    InvalidTransferFee,
+    #[msg("Amount must be greater than zero")]
+    InvalidFundsAmount,
+    #[msg("Not enough available funds")]
+    InsufficientAvailableFunds,
+    #[msg("Funds vault account is required")]
+    FundsVaultRequired,
+    #[msg("Available funds can only stake SOL wagers")]
+    InternalBalanceSolOnly,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Fee would take the whole wager")]
+    FeeExceedsWager,
+    #[msg("Fee is above the fee cap")]
+    FeeAboveCap,
+    #[msg("Fee cap must be below 100% and not below the current fee, and the minimum fee not above the maximum")]
+    InvalidFeeLimits,
+    #[msg("Not enough fees in the fee vault")]
+    InsufficientFeeBalance,
+    #[msg("Fee split needs distinct recipients with positive shares totalling at most 100%")]
+    InvalidFeeSplit,
+    #[msg("A removed fee split recipient still has fees to claim")]
+    FeeShareNotClaimed,
+    #[msg("Not a fee split recipient")]
+    NotFeeShareRecipient,
+    #[msg("Referral share cannot exceed 100%")]
+    InvalidReferralShare,
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+    #[msg("Not the referrer of this player")]
+    NotReferrer,
+    #[msg("Fee is higher than the maximum the player accepted")]
+    FeeAboveMaximum,
+    #[msg("Game wager differs from the expected wager")]
+    UnexpectedWager,
+    #[msg("New authority must differ from the current authority")]
+    InvalidAuthorityTransfer,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Only the proposed authority can accept the transfer")]
+    NotPendingAuthority,
+    #[msg("New games and deposits are paused")]
+    NewGamesPaused,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Admin change delay has not passed yet")]
+    AdminChangeNotReady,
+    #[msg("Admin change delay must be between 0 and 30 days")]
+    InvalidAdminChangeDelay,
+    #[msg("Council needs distinct members (at most 10) and a threshold between 1 and the member count")]
+    InvalidCouncil,
+    #[msg("Admin council account is required")]
+    CouncilRequired,
+    #[msg("Not an admin council member")]
+    NotCouncilMember,
+    #[msg("Member already approved this admin change")]
+    AlreadyApproved,
+    #[msg("Admin change doesn't have enough council approvals")]
+    NotEnoughApprovals,
+    #[msg("Cancelling a rematch needs the game it was requested from")]
+    PreviousGameRequired,
+    #[msg("SPL games need the players' stats accounts for the game's mint")]
+    MintStatsRequired,
+    #[msg("Pass each fee share recipient's token account, in fee split order")]
+    FeeShareAccountsRequired,
+    #[msg("The player's referrer's referral account is required")]
+    ReferralAccountRequired,
+    #[msg("Creating a player profile needs the player's referral link account")]
+    ReferralLinkRequired,
+    #[msg("This player already has a different referrer")]
+    ReferrerAlreadySet,
+    #[msg("Pass a shared referrer's account once, as player1's")]
+    DuplicateReferralAccount,
+    #[msg("Global state is already on the current layout")]
+    AlreadyMigrated,
+    #[msg("This admin change needs the wager tier it updates")]
+    WagerTierRequired,
+    #[msg("Only the council member who queued this admin change can cancel it")]
+    NotProposer,
+    #[msg("The admin council governs this: queue an admin change instead")]
+    CouncilGoverned,
+    #[msg("Once the council is enabled this call needs an approved admin change")]
+    ApprovedChangeRequired,
+    #[msg("The approved admin change is for a different call")]
+    ApprovedChangeMismatch,
+    #[msg("This admin change approves a single call and is used up by that call")]
+    ApprovedCallNotExecutable,
+    #[msg("Pass the member who queued the approved admin change, who gets its rent back")]
+    ProposerRequired,
+    #[msg("Player profile is still in the deployed layout: call migrate_player_profile first")]
+    ProfileNotMigrated,
+    #[msg("Migrating a game in play needs its players' profiles")]
+    PlayerProfileRequired,
 }
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[event]
+pub struct GameSettled {
+    pub game_id: u64,
+    pub player1: Pubkey,
+    pub player2: Pubkey,
+    pub wager: u64,
+    pub mint: Option<Pubkey>,         // None = SOL
+    pub fee_per_player: u64,
+    pub player1_move: Option<Move>,
+    pub player2_move: Option<Move>,
+    pub winner_type: Winner,
+    pub winner_address: Option<Pubkey>,
+    pub series_length: u8,
+    pub round_results: Vec<Winner>,   // Decisive rounds only; empty for single-round games
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GameCancelled {
+    pub game_id: u64,
+    pub player1: Pubkey,
+    pub wager: u64,
+    pub refunded: u64,              // Stake returned to player1, in the game's currency (rent not included)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GameClosed {
+    pub game_id: u64,
+    pub rent_receiver: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenPayout {
+    pub game_id: u64,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,          // Recipient's token account
+    pub amount: u64,                // Sent from the escrow
+    pub transfer_fee: u64,          // Token-2022 transfer fee withheld from the recipient
+    pub net_amount: u64,            // What the recipient actually received
+}
+
+#[event]
+pub struct PlayerProfileClosed {
+    pub player: Pubkey,
+    pub refunded_funds: u64,
+    pub total_games_played: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsDeposited {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub available_funds: u64,       // Balance after the deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsWithdrawn {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub available_funds: u64,       // Balance after the withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Option<Pubkey>,       // None = SOL
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub total_fees_withdrawn: u64,  // Lifetime SOL total after this withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub referral_earnings: u64,     // Referrer's lifetime total after this claim
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub new_games_paused: bool,
+    pub paused: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminChangeQueued {
+    pub change_id: u64,
+    pub change: AdminChange,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminChangeExecuted {
+    pub change_id: u64,
+    pub change: AdminChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminChangeCancelled {
+    pub change_id: u64,
+    pub change: AdminChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminChangeApproved {
+    pub change_id: u64,
+    pub member: Pubkey,
+    pub approvals: u8,              // Approvals from current members so far
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
@@ -1,23 +1,42 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 pub mod errors;
+pub mod events;
 pub mod state;
+pub mod token_escrow;
 
 use errors::*;
+use events::*;
 use state::*;
+use token_escrow::*;
 
 declare_id!("3S9Go4XvdE9bH8UjGmyDqEpaEHLSt7BMLGZEw5jB7DLP");
 
 // Constants
 const REVEAL_TIMEOUT_SECONDS: i64 = 300; // 5 minutes - secure against 1-2s validator skewing
+const COMMIT_TIMEOUT_SECONDS: i64 = 300; // 5 minutes after player2 joins
+const INVITE_TIMEOUT_SECONDS: i64 = 86_400; // 24 hours for the invited player to join
+const DEFAULT_MIN_WAGER: u64 = 10_000_000; // 0.01 SOL
+const DEFAULT_MAX_WAGER: u64 = 1_000_000_000; // 1 SOL
+const DEFAULT_FEE_BPS: u16 = 100; // 1%
+const DEFAULT_MAX_FEE_BPS: u16 = 1_000; // 10%
+const DEFAULT_ADMIN_CHANGE_DELAY: i64 = 86_400; // 24 hours between queueing and executing fee changes
+const MAX_ADMIN_CHANGE_DELAY: i64 = 2_592_000; // 30 days, so a typo can't lock parameters for good
+const LEGACY_AUTHORITY_OFFSET: usize = 8 + 8 + 8; // Discriminator, game_counter, total_games_completed
+const LEGACY_GLOBAL_STATE_LEN: usize = 8 + 8 + 8 + 32 + 32 + 8 + 1; // Deployed layout, up to and including bump
+const LEGACY_PROFILE_NAME_OFFSET: usize = 8 + 32; // Discriminator, player
+const LEGACY_PROFILE_TAIL_LEN: usize = 8 * 4 + 4 * 3 + 8 * 3 + 4 + 4 + 8 + 1; // Deployed fields after the name, up to and including bump
 
 #[program]
 pub mod crypto_pvp {
     use super::*;
 
-    /// Initializes the global state - can only be called once due to #[account(init)]
+    /// Initializes the global state - can only be called once due to #[account(init)],
+    /// and only by the program's upgrade authority
     pub fn initialize_global_state(ctx: Context<InitializeGlobalState>, fee_collector: Pubkey) -> Result<()> {
         let global_state = &mut ctx.accounts.global_state;
         
@@ -25,96 +44,739 @@ pub mod crypto_pvp {
         global_state.game_counter = 0;
         global_state.total_games_completed = 0;
         global_state.authority = ctx.accounts.authority.key();
+        global_state.pending_authority = None;
         global_state.fee_collector = fee_collector;
-        global_state.fee_percentage = 1; // Default to 1%
+        global_state.legacy_fee_percentage = 0; // Superseded by fee_bps
+        global_state.version = GLOBAL_STATE_VERSION;
+        global_state.new_games_paused = false;
+        global_state.paused = false;
+        global_state.fee_bps = DEFAULT_FEE_BPS;
+        global_state.max_fee_bps = DEFAULT_MAX_FEE_BPS;
+        global_state.min_fee = 0; // No lamport floor or ceiling on fees by default
+        global_state.max_fee = 0;
+        global_state.min_wager = DEFAULT_MIN_WAGER;
+        global_state.max_wager = DEFAULT_MAX_WAGER;
+        global_state.no_reveal_penalty_percentage = 0; // Default to a full refund
+        global_state.tie_policy = TiePolicy::Refund; // Default to refunding net wagers
+        global_state.total_fees_accrued = 0;
+        global_state.total_fees_withdrawn = 0;
+        global_state.fee_shares = Vec::new(); // Everything goes to the fee collector until a split is set
+        global_state.referral_bps = 0; // Referrals earn nothing until the authority sets a share
+        global_state.unclaimed_referral_fees = 0;
+        global_state.admin_change_delay = DEFAULT_ADMIN_CHANGE_DELAY;
+        global_state.admin_change_counter = 0;
+        global_state.council_enabled = false; // The authority governs fees until a council is set up
         global_state.bump = ctx.bumps.global_state;
+        ctx.accounts.fee_vault.bump = ctx.bumps.fee_vault;
         
         msg!("Global state initialized by authority: {}", ctx.accounts.authority.key());
         msg!("Fee collector set to: {}", fee_collector);
-        msg!("Fee percentage set to: {}%", global_state.fee_percentage);
+        msg!("Fee set to: {} bps (cap: {} bps)", global_state.fee_bps, global_state.max_fee_bps);
         Ok(())
     }
 
-    //TODO function allowing closing of players for users to reclaim rent?
+    /// Bring a global state created by an earlier deployment up to the current layout (only
+    /// authority can call this). The account is grown in place, the authority tops up its rent,
+    /// and the appended fields get the same defaults initialize_global_state uses.
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        let account = ctx.accounts.global_state.to_account_info();
+        require_keys_eq!(*account.owner, crate::ID, GameError::Unauthorized);
+        {
+            let data = account.try_borrow_data()?;
+            require!(data.starts_with(GlobalState::DISCRIMINATOR), GameError::Unauthorized);
+            // The deployed prefix never moves, so the authority can be read before migrating
+            let authority = Pubkey::try_from(&data[LEGACY_AUTHORITY_OFFSET..LEGACY_AUTHORITY_OFFSET + 32])
+                .map_err(|_| GameError::Unauthorized)?;
+            require_keys_eq!(authority, ctx.accounts.authority.key(), GameError::Unauthorized);
+            let version = data.get(LEGACY_GLOBAL_STATE_LEN).copied().unwrap_or(0);
+            require!(version < GLOBAL_STATE_VERSION, GameError::AlreadyMigrated);
+        }
+
+        let new_len = 8 + GlobalState::INIT_SPACE;
+        let rent_shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+        if rent_shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_shortfall,
+            )?;
+        }
+        account.resize(new_len)?; // Appended bytes are zeroed: None, false, 0 and empty until set below
+
+        let mut global_state = GlobalState::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        global_state.version = GLOBAL_STATE_VERSION;
+        // Whole percent to basis points, kept under the default cap
+        let legacy_fee_bps = global_state.legacy_fee_percentage.saturating_mul(100);
+        global_state.fee_bps = legacy_fee_bps.min(DEFAULT_MAX_FEE_BPS as u64) as u16;
+        global_state.max_fee_bps = DEFAULT_MAX_FEE_BPS;
+        global_state.min_wager = DEFAULT_MIN_WAGER;
+        global_state.max_wager = DEFAULT_MAX_WAGER;
+        global_state.tie_policy = TiePolicy::Refund;
+        global_state.admin_change_delay = DEFAULT_ADMIN_CHANGE_DELAY;
+        global_state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        msg!("Global state migrated to version {} by authority: {}",
+             GLOBAL_STATE_VERSION, ctx.accounts.authority.key());
+        msg!("Fee converted from {}% to {} bps", global_state.legacy_fee_percentage, global_state.fee_bps);
+        Ok(())
+    }
+
+    /// Bring a player profile created by an earlier deployment up to the current layout (anyone can
+    /// call this and pays the rent for the grown account). The appended fields start empty.
+    pub fn migrate_player_profile(ctx: Context<MigratePlayerProfile>) -> Result<()> {
+        let account = ctx.accounts.player_profile.to_account_info();
+        require_keys_eq!(*account.owner, crate::ID, GameError::Unauthorized);
+        let new_len = 8 + PlayerProfile::INIT_SPACE;
+        let legacy_end = {
+            let data = account.try_borrow_data()?;
+            require!(data.starts_with(PlayerProfile::DISCRIMINATOR), GameError::Unauthorized);
+            require!(data.len() < new_len, GameError::AlreadyMigrated);
+            // The name is the only variable-length field, so its length locates the deployed end
+            let name_len = data
+                .get(LEGACY_PROFILE_NAME_OFFSET..LEGACY_PROFILE_NAME_OFFSET + 4)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_le_bytes)
+                .ok_or(GameError::Unauthorized)?;
+            LEGACY_PROFILE_NAME_OFFSET + 4 + name_len as usize + LEGACY_PROFILE_TAIL_LEN
+        };
+
+        let rent_shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+        if rent_shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_shortfall,
+            )?;
+        }
+        account.resize(new_len)?;
+        {
+            // Bytes past the deployed fields can hold a longer name's leftovers, so they are cleared
+            // before the appended fields are read: version 0, no stats and no referrer
+            let mut data = account.try_borrow_mut_data()?;
+            data.get_mut(legacy_end..).ok_or(GameError::Unauthorized)?.fill(0);
+        }
+
+        let mut player_profile = PlayerProfile::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        player_profile.version = PLAYER_PROFILE_VERSION;
+        player_profile.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        msg!("Player profile of {} migrated to version {}", player_profile.player, PLAYER_PROFILE_VERSION);
+        Ok(())
+    }
+
+    /// Bring a game created by the first deployment up to the current layout (anyone can call this
+    /// and pays the rent for the grown account). Its wager becomes lamports and it gets the terms that
+    /// deployment played by: one round, net wagers refunded on ties, no no-reveal penalty. Games still
+    /// in play count toward the players' active games, so their profiles must be migrated first.
+    pub fn migrate_game(ctx: Context<MigrateGame>, game_id: u64) -> Result<()> {
+        let account = ctx.accounts.game.to_account_info();
+        require_keys_eq!(*account.owner, crate::ID, GameError::Unauthorized);
+        let new_len = 8 + Game::INIT_SPACE;
+        let legacy_game = {
+            let data = account.try_borrow_data()?;
+            require!(data.starts_with(Game::DISCRIMINATOR), GameError::Unauthorized);
+            require!(data.len() < new_len, GameError::AlreadyMigrated);
+            LegacyGame::deserialize(&mut &data[8..])?
+        };
+
+        if legacy_game.state != GameState::Finished {
+            let player1_profile = ctx.accounts.player1_profile.as_deref_mut().ok_or(GameError::PlayerProfileRequired)?;
+            require_keys_eq!(player1_profile.player, legacy_game.player1, GameError::PlayerProfileRequired);
+            require!(player1_profile.version == PLAYER_PROFILE_VERSION, GameError::ProfileNotMigrated);
+            player1_profile.active_games += 1;
+            if legacy_game.player2 != Pubkey::default() {
+                let player2_profile = ctx.accounts.player2_profile.as_deref_mut().ok_or(GameError::PlayerProfileRequired)?;
+                require_keys_eq!(player2_profile.player, legacy_game.player2, GameError::PlayerProfileRequired);
+                require!(player2_profile.version == PLAYER_PROFILE_VERSION, GameError::ProfileNotMigrated);
+                player2_profile.active_games += 1;
+            }
+        }
+
+        let rent_shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+        if rent_shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_shortfall,
+            )?;
+        }
+        account.resize(new_len)?;
+        // Fields are rewritten from the start, so everything after the discriminator starts zeroed:
+        // None, false, 0, empty, and the first variant of each enum
+        account.try_borrow_mut_data()?[8..].fill(0);
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut game = Game::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        game.game_id = legacy_game.game_id;
+        game.player1 = legacy_game.player1;
+        game.player2 = legacy_game.player2;
+        game.wager = legacy_game.wager.to_lamports();
+        game.fee_per_player = legacy_game.fee_per_player; // Already collected; the game holds net wagers
+        game.series_length = 1;
+        game.tie_policy = TiePolicy::Refund;
+        game.player1_move_hash = legacy_game.player1_move_hash;
+        game.player2_move_hash = legacy_game.player2_move_hash;
+        game.player1_move = legacy_game.player1_move;
+        game.player2_move = legacy_game.player2_move;
+        game.winner_type = legacy_game.winner_type;
+        game.winner_address = legacy_game.winner_address;
+        // That deployment had no commit deadline and only set the reveal deadline on the first
+        // reveal, so games in those phases get fresh deadlines to be settled by
+        game.commit_deadline = (legacy_game.state == GameState::CommitPhase).then_some(now + COMMIT_TIMEOUT_SECONDS);
+        game.reveal_deadline = match legacy_game.state {
+            GameState::RevealPhase => Some(legacy_game.reveal_deadline.unwrap_or(now + REVEAL_TIMEOUT_SECONDS)),
+            _ => legacy_game.reveal_deadline,
+        };
+        game.state = legacy_game.state;
+        game.bump = legacy_game.bump;
+        game.version = GAME_VERSION;
+        game.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        msg!("Game #{} migrated to version {}: wager {} lamports, fee {} lamports",
+             game_id, GAME_VERSION, game.wager, game.fee_per_player);
+        Ok(())
+    }
+
+    /// Create the fee vault for a deployment initialized before fees were vaulted
+    /// (only authority can call this). Run after migrate_global_state.
+    pub fn init_fee_vault(ctx: Context<InitFeeVault>) -> Result<()> {
+        ctx.accounts.fee_vault.bump = ctx.bumps.fee_vault;
+
+        msg!("Fee vault created by authority: {}", ctx.accounts.authority.key());
+        Ok(())
+    }
+
+    /// Close the caller's player profile and return its rent. Stats are lost; a later
+    /// create_game/join_game re-creates the profile from scratch via init_if_needed.
+    pub fn close_player_profile(ctx: Context<ClosePlayerProfile>) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let player_profile = &ctx.accounts.player_profile;
+
+        // Settlement needs both profiles, so they must outlive every game in play
+        require!(player_profile.active_games == 0, GameError::PlayerHasActiveGames);
+
+        // The internal balance lives in the funds vault, refund it along with the rent
+        let refunded_funds = player_profile.available_funds;
+        if refunded_funds > 0 {
+            let funds_vault = ctx.accounts.funds_vault.as_ref().ok_or(GameError::FundsVaultRequired)?;
+            **funds_vault.to_account_info().try_borrow_mut_lamports()? -= refunded_funds;
+            **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += refunded_funds;
+        }
+
+        emit!(PlayerProfileClosed {
+            player: player_profile.player,
+            refunded_funds,
+            total_games_played: player_profile.total_games_played,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Player profile closed: {} (refunded funds: {})",
+             ctx.accounts.player.key(), refunded_funds);
+        Ok(())
+    }
+
+    /// Move lamports from the player's wallet into their internal balance (available_funds).
+    /// Games can then be staked from the balance, and pay winnings back into it.
+    pub fn deposit(ctx: Context<DepositFunds>, amount: u64) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
+        require!(amount > 0, GameError::InvalidFundsAmount);
+
+        // Initialize player profile if needed (with default name)
+        initialize_player_profile_if_needed(
+            &mut ctx.accounts.player_profile,
+            &ctx.accounts.player.key(),
+            ctx.bumps.player_profile,
+            ctx.accounts.referral_account.as_mut(),
+            ctx.accounts.referral_link.as_mut(),
+            ctx.bumps.referral_link,
+        )?;
+        ctx.accounts.funds_vault.bump = ctx.bumps.funds_vault;
+
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.funds_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.available_funds += amount;
+
+        emit!(FundsDeposited {
+            player: player_profile.player,
+            amount,
+            available_funds: player_profile.available_funds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Deposited {} lamports for: {}, available funds: {}",
+             amount, player_profile.player, player_profile.available_funds);
+        Ok(())
+    }
+
+    /// Move lamports from the player's internal balance back to their wallet
+    pub fn withdraw(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let player_profile = &mut ctx.accounts.player_profile;
+
+        require!(amount > 0, GameError::InvalidFundsAmount);
+        require!(player_profile.available_funds >= amount, GameError::InsufficientAvailableFunds);
+
+        // The vault is program-owned, so lamports move out directly
+        player_profile.available_funds -= amount;
+        **ctx.accounts.funds_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        emit!(FundsWithdrawn {
+            player: player_profile.player,
+            amount,
+            available_funds: player_profile.available_funds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Withdrew {} lamports for: {}, available funds: {}",
+             amount, player_profile.player, player_profile.available_funds);
+        Ok(())
+    }
 
-    // Creates a new game
-    pub fn create_game(ctx: Context<CreateGame>, wager: WagerAmount) -> Result<()> {
+    // Creates a new game. max_fee_lamports caps the fee the player accepts (raw token units for
+    // SPL wagers), in case the authority changes fees before the transaction lands.
+    pub fn create_game(
+        ctx: Context<CreateGame>,
+        wager: u64,
+        series_length: u8,
+        invited_player: Option<Pubkey>,
+        join_secret_hash: Option<[u8; 32]>,
+        max_fee_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
         let global_state = &mut ctx.accounts.global_state;
         let game = &mut ctx.accounts.game;
+        let wager_tier = ctx.accounts.wager_tier.as_deref();
+        let mint = ctx.accounts.token.mint.as_ref().map(|mint| mint.key()); // None = SOL wager
+
+        let fee = wager_fee(global_state, wager, mint, wager_tier)?;
+        require!(fee <= max_fee_lamports, GameError::FeeAboveMaximum);
+        // Best of 1, 3, 5 or 7 so a series always has a majority winner
+        require!(
+            series_length % 2 == 1 && series_length <= MAX_SERIES_LENGTH,
+            GameError::InvalidSeriesLength
+        );
+
+        if let Some(invited) = invited_player {
+            require!(invited != ctx.accounts.player.key(), GameError::CannotJoinOwnGame);
+        }
 
         // Initialize player profile if needed (with default name)
         initialize_player_profile_if_needed(
             &mut ctx.accounts.player1_profile,
             &ctx.accounts.player.key(),
             ctx.bumps.player1_profile,
+            ctx.accounts.referral_account.as_mut(),
+            ctx.accounts.referral_link.as_mut(),
+            ctx.bumps.referral_link,
         )?;
         
         // Set game data
-        game.game_id = global_state.game_counter;
-        game.player1 = ctx.accounts.player.key();
-        game.player2 = Pubkey::default();
-        game.wager = wager;
-        game.fee_per_player = wager.fee_per_player(global_state.fee_percentage); // Lock in current fee
-        game.state = GameState::WaitingForPlayer; // Player1 has created, waiting for player2 to join
-        game.winner_type = None;
-        game.winner_address = None;
-        game.bump = ctx.bumps.game;
+        init_game_data(game, global_state, ctx.accounts.player.key(), wager, fee, series_length, ctx.bumps.game)?;
+        game.wager_tier = wager_tier.map(|tier| tier.tier_id);
+        game.mint = mint;
+        game.player1_uses_funds = ctx.accounts.funds_vault.is_some(); // Stake from available_funds
+        game.invited_player = invited_player; // None = open to anyone
+        game.join_secret_hash = join_secret_hash; // None = no password
+        game.invite_expiry = match invited_player {
+            Some(_) => Some(Clock::get()?.unix_timestamp + INVITE_TIMEOUT_SECONDS),
+            None => None,
+        };
         
         // Process wager with fee collection
-        process_wager(
+        let deposit = ctx.accounts.token.deposit(
+            game,
+            &ctx.accounts.fee_vault.to_account_info(),
             &ctx.accounts.player.to_account_info(),
-            &ctx.accounts.fee_collector.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let fee_accrued = process_wager(
+            &ctx.accounts.player.to_account_info(),
+            &mut ctx.accounts.player1_profile,
+            &ctx.accounts.fee_vault.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
             game,
+            deposit.as_ref(),
+            ctx.accounts.funds_vault.as_ref().map(|vault| vault.to_account_info()).as_ref(),
         )?;
+        global_state.accrue_player_fee(&ctx.accounts.player1_profile, ctx.accounts.referral_account.as_deref_mut(), fee_accrued)?;
 
         // Update player1's wagering stats
-        let wager_lamports = wager.to_lamports();
-        ctx.accounts.player1_profile.total_wagered += wager_lamports;
+        let mint_stats = init_player_mint_stats_if_needed(
+            ctx.accounts.player_mint_stats.as_mut(),
+            &ctx.accounts.player.key(),
+            game,
+            ctx.bumps.player_mint_stats,
+        )?;
+        ctx.accounts.player1_profile.record_wagered(mint_stats, game, wager)?;
+        ctx.accounts.player1_profile.active_games += 1;
         // Update global stats
         global_state.game_counter += 1;
         
-        msg!("Game #{} created by player1: {}, wager: {} lamports (fee: {})", 
-             game.game_id, ctx.accounts.player.key(), wager_lamports, game.fee_per_player);
+        msg!("Game #{} created by player1: {}, wager: {} lamports (fee: {}), best of {}", 
+             game.game_id, ctx.accounts.player.key(), wager, game.fee_per_player, game.series_length);
+        if let Some(mint) = game.mint {
+            msg!("Wager paid in SPL token: {} (amounts in raw token units)", mint);
+        }
+        if let Some(invited) = game.invited_player {
+            msg!("Private game for: {}, invite expires at: {}", invited, game.invite_expiry.unwrap());
+        }
+        if game.join_secret_hash.is_some() {
+            msg!("Game is password protected");
+        }
         Ok(())
     }
 
-    pub fn join_game(ctx: Context<JoinGame>, _game_id: u64) -> Result<()> {
+    /// Join a waiting game. The game's locked wager and fee must match the terms the player signed for.
+    pub fn join_game(
+        ctx: Context<JoinGame>,
+        _game_id: u64,
+        join_secret: Option<[u8; 32]>,
+        expected_wager: u64,
+        max_fee_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
         let game = &mut ctx.accounts.game;
+        require!(game.wager == expected_wager, GameError::UnexpectedWager);
+        require!(game.fee_per_player <= max_fee_lamports, GameError::FeeAboveMaximum);
 
         // Initialize player profile if needed (with default name)
         initialize_player_profile_if_needed(
             &mut ctx.accounts.player2_profile,
             &ctx.accounts.player.key(),
             ctx.bumps.player2_profile,
+            ctx.accounts.referral_account.as_mut(),
+            ctx.accounts.referral_link.as_mut(),
+            ctx.bumps.referral_link,
         )?;
         
         require!(game.state == GameState::WaitingForPlayer, GameError::InvalidGameState);
         require!(game.player1 != ctx.accounts.player.key(), GameError::CannotJoinOwnGame);
+        if let Some(invited) = game.invited_player {
+            require!(invited == ctx.accounts.player.key(), GameError::NotInvitedPlayer);
+            let expiry = game.invite_expiry.ok_or(GameError::NoDeadlineSet)?;
+            require!(Clock::get()?.unix_timestamp <= expiry, GameError::InviteExpired);
+        }
+        if let Some(secret_hash) = game.join_secret_hash {
+            // The secret becomes public with this transaction, so it is only accepted together
+            // with a ticket committed by this same player in an earlier slot (see request_join)
+            let secret = join_secret.ok_or(GameError::InvalidJoinSecret)?;
+            let ticket = ctx.accounts.join_ticket.as_ref().ok_or(GameError::JoinTicketRequired)?;
+            require!(Clock::get()?.slot > ticket.slot, GameError::JoinTicketTooRecent);
+            require!(hash(&secret).to_bytes() == secret_hash, GameError::InvalidJoinSecret);
+            require!(
+                join_commitment(&secret, &ctx.accounts.player.key()) == ticket.commitment,
+                GameError::InvalidJoinSecret
+            );
+        }
         
-        game.player2 = ctx.accounts.player.key();
-        
+        let deposit = ctx.accounts.token.deposit(
+            game,
+            &ctx.accounts.fee_vault.to_account_info(),
+            &ctx.accounts.player.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let fee_accrued = seat_player2(
+            game,
+            &ctx.accounts.player.to_account_info(),
+            &mut ctx.accounts.player2_profile,
+            &ctx.accounts.fee_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            deposit.as_ref(),
+            ctx.accounts.funds_vault.as_ref().map(|vault| vault.to_account_info()).as_ref(),
+        )?;
+        ctx.accounts.global_state.accrue_player_fee(
+            &ctx.accounts.player2_profile,
+            ctx.accounts.referral_account.as_deref_mut(),
+            fee_accrued,
+        )?;
+
+        // Update player2's wagering stats
+        let mint_stats = init_player_mint_stats_if_needed(
+            ctx.accounts.player_mint_stats.as_mut(),
+            &ctx.accounts.player.key(),
+            game,
+            ctx.bumps.player_mint_stats,
+        )?;
+        ctx.accounts.player2_profile.record_wagered(mint_stats, game, game.wager)
+    }
+
+    /// Offer the opponent of a finished game a new game at the same wager.
+    /// The requester becomes player1 and escrows their wager; only the opponent can accept.
+    /// The fee is re-locked at current values, so it must not exceed the requester's bound.
+    pub fn request_rematch(ctx: Context<RequestRematch>, previous_game_id: u64, max_fee_lamports: u64) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
+        let global_state = &mut ctx.accounts.global_state;
+        let previous_game = &mut ctx.accounts.previous_game;
+        let game = &mut ctx.accounts.game;
+        let player = ctx.accounts.player.key();
+
+        require!(previous_game.state == GameState::Finished, GameError::InvalidGameState);
+        require!(
+            player == previous_game.player1 || player == previous_game.player2,
+            GameError::NotPlayerInGame
+        );
+        require!(previous_game.rematch_game_id.is_none(), GameError::RematchAlreadyRequested);
+
+        let opponent = if player == previous_game.player1 { previous_game.player2 } else { previous_game.player1 };
+
+        // Initialize player profile if needed (profile may have been closed since the last game)
+        initialize_player_profile_if_needed(
+            &mut ctx.accounts.player1_profile,
+            &player,
+            ctx.bumps.player1_profile,
+            ctx.accounts.referral_account.as_mut(),
+            ctx.accounts.referral_link.as_mut(),
+            ctx.bumps.referral_link,
+        )?;
+
+        // Same players, same stakes; fee and penalty are re-locked at current values
+        let wager_tier = ctx.accounts.wager_tier.as_deref();
+        require!(
+            wager_tier.map(|tier| tier.tier_id) == previous_game.wager_tier,
+            GameError::WagerTierMismatch
+        );
+        let fee = wager_fee(global_state, previous_game.wager, previous_game.mint, wager_tier)?;
+        require!(fee <= max_fee_lamports, GameError::FeeAboveMaximum);
+        init_game_data(game, global_state, player, previous_game.wager, fee, previous_game.series_length, ctx.bumps.game)?;
+        game.wager_tier = previous_game.wager_tier;
+        game.mint = previous_game.mint;
+        game.player1_uses_funds = ctx.accounts.funds_vault.is_some(); // Stake from available_funds
+        game.invited_player = Some(opponent); // Keeps strangers out
+        game.invite_expiry = Some(Clock::get()?.unix_timestamp + INVITE_TIMEOUT_SECONDS);
+        game.previous_game_id = Some(previous_game_id);
+        previous_game.rematch_game_id = Some(game.game_id);
+
         // Process wager with fee collection
-        process_wager(
+        let deposit = ctx.accounts.token.deposit(
+            game,
+            &ctx.accounts.fee_vault.to_account_info(),
+            &ctx.accounts.player.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let fee_accrued = process_wager(
+            &ctx.accounts.player.to_account_info(),
+            &mut ctx.accounts.player1_profile,
+            &ctx.accounts.fee_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            game,
+            deposit.as_ref(),
+            ctx.accounts.funds_vault.as_ref().map(|vault| vault.to_account_info()).as_ref(),
+        )?;
+        global_state.accrue_player_fee(&ctx.accounts.player1_profile, ctx.accounts.referral_account.as_deref_mut(), fee_accrued)?;
+
+        // Update requester's wagering stats
+        let mint_stats = init_player_mint_stats_if_needed(
+            ctx.accounts.player_mint_stats.as_mut(),
+            &player,
+            game,
+            ctx.bumps.player_mint_stats,
+        )?;
+        ctx.accounts.player1_profile.record_wagered(mint_stats, game, game.wager)?;
+        ctx.accounts.player1_profile.active_games += 1;
+        // Update global stats
+        global_state.game_counter += 1;
+
+        msg!("Rematch of game #{} requested by: {}, new game #{} for: {}",
+             previous_game_id, player, game.game_id, opponent);
+        Ok(())
+    }
+
+    /// Accept a rematch offer; works like join_game but only for rematch games.
+    /// The game's locked wager and fee must match the terms the player signed for.
+    pub fn accept_rematch(
+        ctx: Context<AcceptRematch>,
+        _game_id: u64,
+        expected_wager: u64,
+        max_fee_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
+        let game = &mut ctx.accounts.game;
+        require!(game.wager == expected_wager, GameError::UnexpectedWager);
+        require!(game.fee_per_player <= max_fee_lamports, GameError::FeeAboveMaximum);
+
+        // Initialize player profile if needed (profile may have been closed since the last game)
+        initialize_player_profile_if_needed(
+            &mut ctx.accounts.player2_profile,
+            &ctx.accounts.player.key(),
+            ctx.bumps.player2_profile,
+            ctx.accounts.referral_account.as_mut(),
+            ctx.accounts.referral_link.as_mut(),
+            ctx.bumps.referral_link,
+        )?;
+
+        require!(game.state == GameState::WaitingForPlayer, GameError::InvalidGameState);
+        require!(game.previous_game_id.is_some(), GameError::NotARematch);
+        require!(game.invited_player == Some(ctx.accounts.player.key()), GameError::NotInvitedPlayer);
+        let expiry = game.invite_expiry.ok_or(GameError::NoDeadlineSet)?;
+        require!(Clock::get()?.unix_timestamp <= expiry, GameError::InviteExpired);
+
+        let deposit = ctx.accounts.token.deposit(
+            game,
+            &ctx.accounts.fee_vault.to_account_info(),
             &ctx.accounts.player.to_account_info(),
-            &ctx.accounts.fee_collector.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let fee_accrued = seat_player2(
             game,
+            &ctx.accounts.player.to_account_info(),
+            &mut ctx.accounts.player2_profile,
+            &ctx.accounts.fee_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            deposit.as_ref(),
+            ctx.accounts.funds_vault.as_ref().map(|vault| vault.to_account_info()).as_ref(),
+        )?;
+        ctx.accounts.global_state.accrue_player_fee(
+            &ctx.accounts.player2_profile,
+            ctx.accounts.referral_account.as_deref_mut(),
+            fee_accrued,
         )?;
 
         // Update player2's wagering stats
-        let wager_lamports = game.wager.to_lamports();
-        ctx.accounts.player2_profile.total_wagered += wager_lamports;
-        // After both players joined, advance to commit phase
-        game.state = GameState::CommitPhase;
-        
-        //TODO change to event emition?
-        msg!("Player2 joined game #{}: {} (fee: {})", game.game_id, ctx.accounts.player.key(), game.fee_per_player);
-        msg!("Game advanced to commit phase");
+        let mint_stats = init_player_mint_stats_if_needed(
+            ctx.accounts.player_mint_stats.as_mut(),
+            &ctx.accounts.player.key(),
+            game,
+            ctx.bumps.player_mint_stats,
+        )?;
+        ctx.accounts.player2_profile.record_wagered(mint_stats, game, game.wager)
+    }
+
+    /// First step to join a password-protected game: commit to hash(secret || player)
+    /// without revealing the secret. join_game must land in a later slot.
+    pub fn request_join(ctx: Context<RequestJoin>, game_id: u64, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.global_state.require_new_games_allowed()?;
+        let game = &ctx.accounts.game;
+        let ticket = &mut ctx.accounts.join_ticket;
+
+        require!(game.state == GameState::WaitingForPlayer, GameError::InvalidGameState);
+        require!(game.join_secret_hash.is_some(), GameError::GameNotPrivate);
+
+        // Re-requesting overwrites the previous commitment and restarts the slot check
+        ticket.game_id = game_id;
+        ticket.player = ctx.accounts.player.key();
+        ticket.commitment = commitment;
+        ticket.slot = Clock::get()?.slot;
+        ticket.bump = ctx.bumps.join_ticket;
+
+        msg!("Join requested for game #{} by: {} at slot {}", game_id, ticket.player, ticket.slot);
+        Ok(())
+    }
+
+    /// Close an unused join ticket (e.g. the game was cancelled or joined by someone else)
+    pub fn close_join_ticket(ctx: Context<CloseJoinTicket>, game_id: u64) -> Result<()> {
+        msg!("Join ticket for game #{} closed by: {}", game_id, ctx.accounts.player.key());
+        Ok(())
+    }
+
+    /// Drop the invite and/or password on a private game so anyone can join (only player1, before anyone joined)
+    pub fn open_game_to_public(ctx: Context<OpenGameToPublic>, _game_id: u64) -> Result<()> {
+        let game = &mut ctx.accounts.game;
+
+        require!(game.state == GameState::WaitingForPlayer, GameError::InvalidGameState);
+        require!(game.player1 == ctx.accounts.player.key(), GameError::NotGameCreator);
+        require!(
+            game.invited_player.is_some() || game.join_secret_hash.is_some(),
+            GameError::GameNotPrivate
+        );
+
+        if let Some(previous_invite) = game.invited_player.take() {
+            msg!("Invite to {} dropped", previous_invite);
+        }
+        game.invite_expiry = None;
+        game.join_secret_hash = None;
+
+        msg!("Game #{} opened to the public by player1", game.game_id);
+        Ok(())
+    }
+
+    /// Cancel a game nobody has joined yet. Closing the game account returns
+    /// the escrowed net wager and the rent deposit to player1; the fee is not refunded
+    /// unless the game still holds it (RefundWithFees tie policy).
+    pub fn cancel_game(ctx: Context<CancelGame>, _game_id: u64) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let game = &ctx.accounts.game;
+
+        require!(game.state == GameState::WaitingForPlayer, GameError::InvalidGameState);
+        require!(game.player1 == ctx.accounts.player.key(), GameError::NotGameCreator);
+
+        // A cancelled rematch frees the previous game for another rematch request
+        if game.previous_game_id.is_some() {
+            let previous_game = ctx.accounts.previous_game.as_ref().ok_or(GameError::PreviousGameRequired)?;
+            // A previous game closed since then has no rematch link left to clear
+            if *previous_game.owner == crate::ID && !previous_game.data_is_empty() {
+                let mut previous_game_data = Game::try_deserialize(&mut &previous_game.try_borrow_data()?[..])?;
+                if previous_game_data.rematch_game_id == Some(game.game_id) {
+                    previous_game_data.rematch_game_id = None;
+                    previous_game_data.try_serialize(&mut &mut previous_game.try_borrow_mut_data()?[..])?;
+                }
+            }
+        }
+
+        ctx.accounts.player1_profile.total_games_cancelled += 1;
+        ctx.accounts.player1_profile.active_games = ctx.accounts.player1_profile.active_games
+            .checked_sub(1)
+            .ok_or(GameError::MathOverflow)?;
+
+        // A stake taken from available_funds goes back there; closing the game returns the rent
+        if game.player1_uses_funds {
+            let funds_vault = ctx.accounts.funds_vault.as_ref().ok_or(GameError::FundsVaultRequired)?;
+            let refund = game.escrowed_per_player()?;
+            **game.to_account_info().try_borrow_mut_lamports()? -= refund;
+            **funds_vault.to_account_info().try_borrow_mut_lamports()? += refund;
+            ctx.accounts.player1_profile.available_funds += refund;
+        }
+
+        // Closing the game only returns lamports, so escrowed tokens (with any deposit surplus)
+        // are refunded here
+        let refunded = match ctx.accounts.token.escrow(game)? {
+            Some(token) => {
+                let balance = escrow_balance(game, Some(&token))?;
+                ctx.accounts.token.close_escrow(game, &token, &ctx.accounts.player.to_account_info())?;
+                balance
+            }
+            None => game.escrowed_per_player()?,
+        };
+
+        emit!(GameCancelled {
+            game_id: game.game_id,
+            player1: game.player1,
+            wager: game.wager,
+            refunded,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Game #{} cancelled by player1: {}, refunded {} (plus rent)",
+             game.game_id, ctx.accounts.player.key(), refunded);
         Ok(())
     }
 
     pub fn reveal_move(ctx: Context<RevealMove>, _game_id: u64, move_choice: Move, salt: [u8; 32]) -> Result<()> {
+        // Not paused, like commit_move: only timeout claims wait for the pause to end
         let game = &mut ctx.accounts.game;
         let player = ctx.accounts.player.key();
         
@@ -123,6 +785,10 @@ pub mod crypto_pvp {
             player == game.player1 || player == game.player2,
             GameError::NotPlayerInGame
         );
+        if let Some(deadline) = game.reveal_deadline {
+            // Late reveals would race a pending timeout claim or settlement
+            require!(Clock::get()?.unix_timestamp <= deadline, GameError::RevealDeadlinePassed);
+        }
 
         // Verify the revealed move matches the committed hash
         // Format: [move_byte] + [32_salt_bytes] for secure hash computation
@@ -153,19 +819,32 @@ pub mod crypto_pvp {
 
         // Check if both players have revealed
         if game.player1_move.is_some() && game.player2_move.is_some() { // Finish match
-            let winner_type = determine_winner(
+            let mut winner_type = determine_winner(
                 game.player1_move.unwrap(),
                 game.player2_move.unwrap(),
             );
+
+            // Series games only finish once a player has won the majority of rounds
+            if game.series_length > 1 {
+                match game.record_round(winner_type) {
+                    Some(series_winner) => winner_type = series_winner,
+                    None => {
+                        let (player1_wins, player2_wins) = game.round_wins();
+                        msg!("Round result: {:?}. Series score {}-{} (best of {})",
+                             winner_type, player1_wins, player2_wins, game.series_length);
+                        start_next_round(game)?;
+                        return Ok(());
+                    }
+                }
+            } else if winner_type == Winner::Tie && game.tie_policy == TiePolicy::Replay {
+                game.round_ties = game.round_ties.saturating_add(1);
+                msg!("Round tied, replaying with new commits");
+                start_next_round(game)?;
+                return Ok(());
+            }
             
             game.winner_type = Some(winner_type);
-            game.winner_address = match winner_type {
-                Winner::Player1 => Some(game.player1),
-                Winner::Player2 => Some(game.player2),
-                Winner::Tie => None, //TODO lets discuss draws later.
-                Winner::Player1OpponentForfeit => Some(game.player1),
-                Winner::Player2OpponentForfeit => Some(game.player2),
-            };
+            game.winner_address = game.winner_address(winner_type);
             
             game.state = GameState::Finished;
             
@@ -177,24 +856,33 @@ pub mod crypto_pvp {
             update_player_stats(
                 &mut ctx.accounts.player1_profile,
                 &mut ctx.accounts.player2_profile,
+                ctx.accounts.token.player1_mint_stats.as_deref_mut(),
+                ctx.accounts.token.player2_mint_stats.as_deref_mut(),
                 winner_type,
                 game,
             )?;
             
             // Handle payout to winner(s)
-            payout_winner(
+            let payees = ctx.accounts.token.payees(
+                game,
+                &payout_account(game.player1_uses_funds, &ctx.accounts.player1, &ctx.accounts.funds_vault)?,
+                &payout_account(game.player2_uses_funds, &ctx.accounts.player2, &ctx.accounts.funds_vault)?,
+                &ctx.accounts.fee_vault.to_account_info(),
+            )?;
+            settle_payouts(
                 game,
-                &ctx.accounts.player1,
-                &ctx.accounts.player2,
+                &payees,
+                &mut ctx.accounts.player1_profile,
+                &mut ctx.accounts.player2_profile,
+                &mut ctx.accounts.referrals,
+                global_state,
                 winner_type,
             )?;
+            emit_game_settled(game)?;
             
             msg!("Game #{} finished! Winner: {:?}, address: {:?}", game.game_id, game.winner_type, game.winner_address);
-        } else { // First player reveal, set the timeout
-            require!(game.reveal_deadline.is_none(), GameError::InvalidGameState); // just in case
-            let clock = Clock::get()?;
-            game.reveal_deadline = Some(clock.unix_timestamp + REVEAL_TIMEOUT_SECONDS);
-            msg!("Reveal deadline set: {}", game.reveal_deadline.unwrap());
+        } else { // First player reveal, opponent must reveal before the deadline
+            msg!("Waiting for the other player to reveal before: {:?}", game.reveal_deadline);
         }
 
         Ok(())
@@ -202,6 +890,7 @@ pub mod crypto_pvp {
 
     /// Claim victory when opponent fails to reveal within timeout
     pub fn claim_timeout_victory(ctx: Context<ClaimTimeoutVictory>) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
         let game = &mut ctx.accounts.game;
         let player = ctx.accounts.player.key();
         let clock = Clock::get()?;
@@ -241,60 +930,660 @@ pub mod crypto_pvp {
         update_player_stats(
             &mut ctx.accounts.player1_profile,
             &mut ctx.accounts.player2_profile,
+            ctx.accounts.token.player1_mint_stats.as_deref_mut(),
+            ctx.accounts.token.player2_mint_stats.as_deref_mut(),
             winner_type,
             game,
         )?;
 
         // Handle payout to winner
-        payout_winner(
+        let payees = ctx.accounts.token.payees(
             game,
-            &ctx.accounts.player1,
-            &ctx.accounts.player2,
+            &payout_account(game.player1_uses_funds, &ctx.accounts.player1, &ctx.accounts.funds_vault)?,
+            &payout_account(game.player2_uses_funds, &ctx.accounts.player2, &ctx.accounts.funds_vault)?,
+            &ctx.accounts.fee_vault.to_account_info(),
+        )?;
+        settle_payouts(
+            game,
+            &payees,
+            &mut ctx.accounts.player1_profile,
+            &mut ctx.accounts.player2_profile,
+            &mut ctx.accounts.referrals,
+            global_state,
             winner_type,
         )?;
+        emit_game_settled(game)?;
 
         msg!("Game #{} finished by opponent forefit! Winner: {:?}, address: {:?}", 
              game.game_id, game.winner_type, game.winner_address);
         Ok(())
     }
 
-    /// Update player name (can be called anytime)
-    pub fn update_player_name(ctx: Context<UpdatePlayerName>, new_name: String) -> Result<()> {
-        let player_profile = &mut ctx.accounts.player_profile;
-        player_profile.name = new_name;
-        msg!("Player {} updated name to: {}", ctx.accounts.player.key(), player_profile.name);
-        Ok(())
-    }
+    /// Settle a game where neither player revealed before the deadline (callable by anyone).
+    /// Both players are refunded minus the no-reveal penalty locked at game creation.
+    pub fn settle_expired_reveal(ctx: Context<SettleExpiredReveal>, _game_id: u64) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let game = &mut ctx.accounts.game;
+        let clock = Clock::get()?;
 
-    /// Update fee collector address (only authority can call this)
-    pub fn update_fee_collector(ctx: Context<UpdateFeeCollector>, new_fee_collector: Pubkey) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-        let old_collector = global_state.fee_collector;
-        global_state.fee_collector = new_fee_collector;
-        
-        msg!("Fee collector updated from {} to {} by authority: {}", 
-             old_collector, new_fee_collector, ctx.accounts.authority.key());
-        Ok(())
-    }
+        require!(game.state == GameState::RevealPhase, GameError::InvalidGameState);
+
+        let deadline = game.reveal_deadline.ok_or(GameError::NoDeadlineSet)?;
+        require!(clock.unix_timestamp > deadline, GameError::DeadlineNotReached);
+        // If exactly one player revealed, they claim through claim_timeout_victory instead
+        require!(
+            game.player1_move.is_none() && game.player2_move.is_none(),
+            GameError::OpponentAlreadyRevealed
+        );
+
+        let winner_type = Winner::NoRevealRefund;
+        game.winner_type = Some(winner_type);
+        game.winner_address = None;
+        game.state = GameState::Finished;
 
-    /// Update fee percentage (only authority can call this)
-    pub fn update_fee_percentage(ctx: Context<UpdateFeeCollector>, new_fee_percentage: u64) -> Result<()> {
+        // Update global stats
         let global_state = &mut ctx.accounts.global_state;
-        let old_percentage = global_state.fee_percentage;
-        global_state.fee_percentage = new_fee_percentage;
-        
-        msg!("Fee percentage updated from {}% to {}% by authority: {}", 
-             old_percentage, new_fee_percentage, ctx.accounts.authority.key());
+        global_state.total_games_completed += 1;
+
+        // Update player profile stats
+        update_player_stats(
+            &mut ctx.accounts.player1_profile,
+            &mut ctx.accounts.player2_profile,
+            ctx.accounts.token.player1_mint_stats.as_deref_mut(),
+            ctx.accounts.token.player2_mint_stats.as_deref_mut(),
+            winner_type,
+            game,
+        )?;
+
+        // Refund both players (minus penalty)
+        let payees = ctx.accounts.token.payees(
+            game,
+            &payout_account(game.player1_uses_funds, &ctx.accounts.player1, &ctx.accounts.funds_vault)?,
+            &payout_account(game.player2_uses_funds, &ctx.accounts.player2, &ctx.accounts.funds_vault)?,
+            &ctx.accounts.fee_vault.to_account_info(),
+        )?;
+        settle_payouts(
+            game,
+            &payees,
+            &mut ctx.accounts.player1_profile,
+            &mut ctx.accounts.player2_profile,
+            &mut ctx.accounts.referrals,
+            global_state,
+            winner_type,
+        )?;
+        emit_game_settled(game)?;
+
+        // Penalty from both players goes to the fee vault
+        let total_penalty = game.total_no_reveal_penalty()?;
+        if total_penalty > 0 {
+            pay_from_escrow(game, payees.token.as_ref(), &payees.fee_vault, total_penalty)?;
+            if payees.token.is_none() {
+                global_state.accrue_fees(total_penalty)?;
+            }
+            msg!("No-reveal penalty: {} to fee vault", total_penalty);
+        }
+
+        msg!("Game #{} settled after neither player revealed, by: {}",
+             game.game_id, ctx.accounts.caller.key());
         Ok(())
     }
 
-    //QUESTION game_id unused variable?
-    pub fn commit_move(ctx: Context<CommitMove>, _game_id: u64, move_hash: [u8; 32]) -> Result<()> {
+    /// Claim the pot (or a refund) when the commit deadline passes without both commits
+    pub fn claim_commit_timeout(ctx: Context<ClaimCommitTimeout>, _game_id: u64) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
         let game = &mut ctx.accounts.game;
         let player = ctx.accounts.player.key();
+        let clock = Clock::get()?;
 
         require!(game.state == GameState::CommitPhase, GameError::InvalidGameState);
-        require!(player == game.player1 || player == game.player2, GameError::NotPlayerInGame);
+        require!(
+            player == game.player1 || player == game.player2,
+            GameError::NotPlayerInGame
+        );
+
+        let deadline = game.commit_deadline.ok_or(GameError::NoDeadlineSet)?;
+        require!(clock.unix_timestamp > deadline, GameError::CommitDeadlineNotReached);
+
+        // Both committed would already have moved the game to RevealPhase
+        let winner_type = match (game.player1_move_hash.is_some(), game.player2_move_hash.is_some()) {
+            (true, false) => {
+                require!(player == game.player1, GameError::ClaimerDidNotCommit);
+                Winner::Player1OpponentNoCommit
+            }
+            (false, true) => {
+                require!(player == game.player2, GameError::ClaimerDidNotCommit);
+                Winner::Player2OpponentNoCommit
+            }
+            (false, false) => Winner::NoCommitRefund,
+            (true, true) => return Err(GameError::InvalidGameState.into()),
+        };
+
+        game.winner_type = Some(winner_type);
+        game.winner_address = game.winner_address(winner_type);
+        game.state = GameState::Finished;
+
+        // Update global stats
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.total_games_completed += 1;
+
+        // Update player profile stats
+        update_player_stats(
+            &mut ctx.accounts.player1_profile,
+            &mut ctx.accounts.player2_profile,
+            ctx.accounts.token.player1_mint_stats.as_deref_mut(),
+            ctx.accounts.token.player2_mint_stats.as_deref_mut(),
+            winner_type,
+            game,
+        )?;
+
+        // Handle payout to winner (or refund both)
+        let payees = ctx.accounts.token.payees(
+            game,
+            &payout_account(game.player1_uses_funds, &ctx.accounts.player1, &ctx.accounts.funds_vault)?,
+            &payout_account(game.player2_uses_funds, &ctx.accounts.player2, &ctx.accounts.funds_vault)?,
+            &ctx.accounts.fee_vault.to_account_info(),
+        )?;
+        settle_payouts(
+            game,
+            &payees,
+            &mut ctx.accounts.player1_profile,
+            &mut ctx.accounts.player2_profile,
+            &mut ctx.accounts.referrals,
+            global_state,
+            winner_type,
+        )?;
+        emit_game_settled(game)?;
+
+        msg!("Game #{} finished by commit timeout! Winner: {:?}, address: {:?}",
+             game.game_id, game.winner_type, game.winner_address);
+        Ok(())
+    }
+
+    /// Close a finished game and return its rent to player1, who paid for the account.
+    /// The outcome was already emitted as a GameSettled event when the game finished.
+    pub fn close_game(ctx: Context<CloseGame>, _game_id: u64) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let game = &ctx.accounts.game;
+        let player = ctx.accounts.player.key();
+
+        require!(game.state == GameState::Finished, GameError::InvalidGameState);
+        require!(game.winner_type.is_some(), GameError::GameNotSettled);
+        require!(
+            player == game.player1 || player == game.player2,
+            GameError::NotPlayerInGame
+        );
+
+        // Escrow rent goes back to player1 as well; settlement left only the deposit rounding surplus
+        if let Some(token) = ctx.accounts.token.escrow(game)? {
+            ctx.accounts.token.close_escrow(game, &token, &ctx.accounts.player1)?;
+        }
+
+        emit!(GameClosed {
+            game_id: game.game_id,
+            rent_receiver: game.player1,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Game #{} closed by: {}, rent returned to player1: {}", game.game_id, player, game.player1);
+        Ok(())
+    }
+
+    /// Update player name (can be called anytime)
+    pub fn update_player_name(ctx: Context<UpdatePlayerName>, new_name: String) -> Result<()> {
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.name = new_name;
+        msg!("Player {} updated name to: {}", ctx.accounts.player.key(), player_profile.name);
+        Ok(())
+    }
+
+    /// First step of an authority transfer: record the proposed new authority, which must then
+    /// call accept_authority itself (only authority can call this, and only until the council is
+    /// enabled; after that transfers go through an AdminChange::Authority change)
+    pub fn propose_authority(ctx: Context<AdminAction>, new_authority: Pubkey) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require_authority(global_state, &ctx.accounts.admin.key())?;
+        require!(new_authority != global_state.authority, GameError::InvalidAuthorityTransfer);
+
+        // A new proposal replaces any pending one
+        global_state.pending_authority = Some(new_authority);
+
+        emit!(AuthorityTransferProposed {
+            authority: global_state.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transfer to {} proposed by authority: {}", new_authority, global_state.authority);
+        Ok(())
+    }
+
+    /// Withdraw a pending authority transfer (only authority can call this, and only until the
+    /// council is enabled)
+    pub fn cancel_authority_transfer(ctx: Context<AdminAction>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require_authority(global_state, &ctx.accounts.admin.key())?;
+        let pending_authority = global_state.pending_authority.take().ok_or(GameError::NoPendingAuthority)?;
+
+        emit!(AuthorityTransferCancelled {
+            authority: global_state.authority,
+            pending_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transfer to {} cancelled by authority: {}", pending_authority, global_state.authority);
+        Ok(())
+    }
+
+    /// Second step of an authority transfer: the proposed authority takes over by signing,
+    /// which proves the address is controlled by someone
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let previous_authority = global_state.authority;
+        global_state.authority = ctx.accounts.new_authority.key();
+        global_state.pending_authority = None;
+
+        emit!(AuthorityTransferred {
+            previous_authority,
+            new_authority: global_state.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transferred from {} to {}", previous_authority, global_state.authority);
+        Ok(())
+    }
+
+    /// Set the pause switches (only authority can call this; once the council is enabled any one
+    /// member can pause, and lifting a pause takes an AdminChange::Pause). Pausing new games stops
+    /// money coming in while games in play still settle; pausing everything also stops timeout
+    /// claims, cancels and withdrawals. Commits and reveals always go through, since their deadlines
+    /// keep running.
+    pub fn set_paused(ctx: Context<AdminAction>, new_games_paused: bool, paused: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let admin = ctx.accounts.admin.key();
+        require_admin(global_state, ctx.accounts.council.as_deref(), &admin)?;
+        if global_state.council_enabled {
+            // A single member can only pull the brake, not release it
+            require!(
+                (new_games_paused || !global_state.new_games_paused) && (paused || !global_state.paused),
+                GameError::CouncilGoverned
+            );
+        }
+        global_state.new_games_paused = new_games_paused;
+        global_state.paused = paused;
+
+        emit!(PauseUpdated {
+            new_games_paused,
+            paused,
+            authority: admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Pause updated (new games: {}, everything: {}) by: {}", new_games_paused, paused, admin);
+        Ok(())
+    }
+
+    /// Hand admin control to an M-of-N admin council. From then on admin changes (including authority
+    /// transfers), fee withdrawals and new wager tiers need threshold approvals; any one member can
+    /// still pause (only authority can call this)
+    pub fn initialize_council(ctx: Context<InitializeCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        validate_council(&members, threshold)?;
+
+        let council = &mut ctx.accounts.council;
+        council.members = members;
+        council.threshold = threshold;
+        council.bump = ctx.bumps.council;
+        ctx.accounts.global_state.council_enabled = true;
+
+        emit!(CouncilUpdated {
+            members: council.members.clone(),
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin council of {} members (threshold {}) initialized by authority: {}",
+             council.members.len(), threshold, ctx.accounts.authority.key());
+        Ok(())
+    }
+
+    /// Queue an admin change. It can be executed by anyone once the admin change delay has passed
+    /// (and the council approved it), so players see it coming; call approvals are used by the call.
+    /// Only the authority can call this, or a council member once the council is enabled.
+    pub fn queue_admin_change(ctx: Context<QueueAdminChange>, change: AdminChange) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let admin = ctx.accounts.admin.key();
+        require_admin(global_state, ctx.accounts.council.as_deref(), &admin)?;
+
+        if change.approves_call() {
+            // Without a council the authority makes these calls directly
+            require!(global_state.council_enabled, GameError::CouncilRequired);
+        } else {
+            // Reject changes that would fail against the current state right away
+            let mut council_preview = ctx.accounts.council.as_deref().cloned();
+            let mut wager_tier_preview = ctx.accounts.wager_tier.as_deref().cloned();
+            apply_admin_change(
+                &mut (**global_state).clone(),
+                council_preview.as_mut(),
+                wager_tier_preview.as_mut(),
+                &change,
+            )?;
+        }
+
+        let pending_change = &mut ctx.accounts.pending_change;
+        pending_change.change_id = global_state.admin_change_counter;
+        pending_change.change = change.clone();
+        pending_change.proposed_by = admin;
+        pending_change.effective_at = Clock::get()?.unix_timestamp + global_state.admin_change_delay;
+        // Queueing counts as the proposing member's approval
+        pending_change.approvals = if global_state.council_enabled { vec![admin] } else { Vec::new() };
+        pending_change.bump = ctx.bumps.pending_change;
+        global_state.admin_change_counter += 1;
+
+        emit!(AdminChangeQueued {
+            change_id: pending_change.change_id,
+            change,
+            effective_at: pending_change.effective_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin change #{} queued by: {}, effective at: {}",
+             pending_change.change_id, pending_change.proposed_by, pending_change.effective_at);
+        Ok(())
+    }
+
+    /// Approve a queued admin change as a council member
+    pub fn approve_admin_change(ctx: Context<ApproveAdminChange>, change_id: u64) -> Result<()> {
+        let member = ctx.accounts.member.key();
+        let council = &ctx.accounts.council;
+        require!(council.members.contains(&member), GameError::NotCouncilMember);
+
+        let pending_change = &mut ctx.accounts.pending_change;
+        require!(!pending_change.approvals.contains(&member), GameError::AlreadyApproved);
+        // Drop approvals from members removed since, so the list stays within its allocated space
+        pending_change.approvals.retain(|approver| council.members.contains(approver));
+        pending_change.approvals.push(member);
+        let approvals = council.approvals(pending_change);
+
+        emit!(AdminChangeApproved {
+            change_id,
+            member,
+            approvals,
+            threshold: council.threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin change #{} approved by: {} ({} of {})", change_id, member, approvals, council.threshold);
+        Ok(())
+    }
+
+    /// Apply a queued admin change once its delay has passed and, with a council, enough
+    /// members approved it (anyone can call this)
+    pub fn execute_admin_change(ctx: Context<ExecuteAdminChange>, change_id: u64) -> Result<()> {
+        let pending_change = &ctx.accounts.pending_change;
+        require!(
+            Clock::get()?.unix_timestamp >= pending_change.effective_at,
+            GameError::AdminChangeNotReady
+        );
+        if ctx.accounts.global_state.council_enabled {
+            let council = ctx.accounts.council.as_deref().ok_or(GameError::CouncilRequired)?;
+            // Approvals from members removed since then don't count
+            require!(
+                council.approvals(pending_change) >= council.threshold,
+                GameError::NotEnoughApprovals
+            );
+        }
+
+        apply_admin_change(
+            &mut ctx.accounts.global_state,
+            ctx.accounts.council.as_deref_mut(),
+            ctx.accounts.wager_tier.as_deref_mut(),
+            &pending_change.change,
+        )?;
+
+        emit!(AdminChangeExecuted {
+            change_id,
+            change: pending_change.change.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin change #{} executed", change_id);
+        Ok(())
+    }
+
+    /// Drop a queued admin change before it is executed. Only the authority can call this,
+    /// or, once the council is enabled, the member who queued it (so one member can't veto the rest).
+    pub fn cancel_admin_change(ctx: Context<CancelAdminChange>, change_id: u64) -> Result<()> {
+        let global_state = &ctx.accounts.global_state;
+        let admin = ctx.accounts.admin.key();
+        require_admin(global_state, ctx.accounts.council.as_deref(), &admin)?;
+        if global_state.council_enabled {
+            require_keys_eq!(admin, ctx.accounts.pending_change.proposed_by, GameError::NotProposer);
+        }
+
+        emit!(AdminChangeCancelled {
+            change_id,
+            change: ctx.accounts.pending_change.change.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin change #{} cancelled by: {}", change_id, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Withdraw accrued SOL fees from the fee vault to the fee collector (only authority can call
+    /// this, or the council through an approved AdminChange::WithdrawFees)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require_approved_call(
+            global_state,
+            ctx.accounts.council.as_deref(),
+            ctx.accounts.approved_change.as_deref(),
+            ctx.accounts.proposer.as_ref().map(|proposer| proposer.key()),
+            &ctx.accounts.admin.key(),
+            &AdminChange::WithdrawFees { amount },
+        )?;
+        let fee_vault = ctx.accounts.fee_vault.to_account_info();
+        // The vault keeps its rent-exempt minimum and what fee shares and referrers haven't claimed yet
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(fee_vault.data_len());
+        let withdrawable = fee_vault
+            .lamports()
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(global_state.unclaimed_fees());
+        require!(amount > 0 && amount <= withdrawable, GameError::InsufficientFeeBalance);
+
+        **fee_vault.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.fee_collector.try_borrow_mut_lamports()? += amount;
+        global_state.record_fees_withdrawn(amount)?;
+
+        emit!(FeesWithdrawn {
+            mint: None,
+            amount,
+            destination: ctx.accounts.fee_collector.key(),
+            total_fees_withdrawn: global_state.total_fees_withdrawn,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Withdrew {} lamports of fees to: {} (accrued: {}, withdrawn: {})",
+             amount, ctx.accounts.fee_collector.key(),
+             global_state.total_fees_accrued, global_state.total_fees_withdrawn);
+        Ok(())
+    }
+
+    /// Claim the caller's accrued share of SOL fees from the fee vault
+    pub fn claim_fee_share(ctx: Context<ClaimFeeShare>) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let global_state = &mut ctx.accounts.global_state;
+        let recipient = ctx.accounts.recipient.key();
+
+        let share = global_state
+            .fee_shares
+            .iter_mut()
+            .find(|share| share.recipient == recipient)
+            .ok_or(GameError::NotFeeShareRecipient)?;
+        let amount = share.claimable;
+        require!(amount > 0, GameError::InsufficientFeeBalance);
+        share.claimable = 0;
+
+        **ctx.accounts.fee_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+        global_state.record_fees_withdrawn(amount)?;
+
+        emit!(FeesWithdrawn {
+            mint: None,
+            amount,
+            destination: recipient,
+            total_fees_withdrawn: global_state.total_fees_withdrawn,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Fee share of {} lamports claimed by: {}", amount, recipient);
+        Ok(())
+    }
+
+    /// Open the caller's referral account. Players can only sign up with a referrer that has one,
+    /// and their games' referral fees accrue to it.
+    pub fn create_referral_account(ctx: Context<CreateReferralAccount>) -> Result<()> {
+        let referral_account = &mut ctx.accounts.referral_account;
+        referral_account.referrer = ctx.accounts.referrer.key();
+        referral_account.bump = ctx.bumps.referral_account;
+
+        msg!("Referral account created for: {}", referral_account.referrer);
+        Ok(())
+    }
+
+    /// Claim all referral fees the caller's referred players have accrued
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        ctx.accounts.global_state.require_not_paused()?;
+        let referral_account = &mut ctx.accounts.referral_account;
+        let amount = referral_account.claimable;
+        require!(amount > 0, GameError::InsufficientFeeBalance);
+        referral_account.claimable = 0;
+        referral_account.total_claimed = referral_account.total_claimed.checked_add(amount).ok_or(GameError::MathOverflow)?;
+
+        **ctx.accounts.fee_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.unclaimed_referral_fees -= amount;
+        global_state.record_fees_withdrawn(amount)?;
+
+        emit!(ReferralFeesClaimed {
+            referrer: referral_account.referrer,
+            amount,
+            referral_earnings: referral_account.total_claimed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Referral fees of {} lamports claimed by: {}", amount, referral_account.referrer);
+        Ok(())
+    }
+
+    /// Withdraw SPL fees held in the fee vault's token account (only authority can call this, or the
+    /// council through an approved AdminChange::WithdrawTokenFees).
+    /// The fee split is paid out of the withdrawn amount: remaining accounts are each fee share
+    /// recipient's ATA for the mint, in fee_shares order; the rest goes to the fee collector.
+    /// Token fees are not part of the lamport fee totals.
+    pub fn withdraw_token_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawTokenFees<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        require_approved_call(
+            &ctx.accounts.global_state,
+            ctx.accounts.council.as_deref(),
+            ctx.accounts.approved_change.as_deref(),
+            ctx.accounts.proposer.as_ref().map(|proposer| proposer.key()),
+            &ctx.accounts.admin.key(),
+            &AdminChange::WithdrawTokenFees { mint: ctx.accounts.mint.key(), amount },
+        )?;
+        require!(
+            amount > 0 && amount <= ctx.accounts.fee_vault_token_account.amount,
+            GameError::InsufficientFeeBalance
+        );
+        let fee_shares = &ctx.accounts.global_state.fee_shares;
+        require!(ctx.remaining_accounts.len() == fee_shares.len(), GameError::FeeShareAccountsRequired);
+
+        let mint = ctx.accounts.mint.key();
+        let token_program = ctx.accounts.token_program.key();
+        let mut payouts = Vec::with_capacity(fee_shares.len() + 1);
+        let mut collector_amount = amount;
+        for (share, destination) in fee_shares.iter().zip(ctx.remaining_accounts) {
+            require_keys_eq!(
+                destination.key(),
+                get_associated_token_address_with_program_id(&share.recipient, &mint, &token_program),
+                GameError::InvalidTokenAccount
+            );
+            // Shares round down, so rounding dust stays with the fee collector
+            let cut = (amount as u128 * share.bps as u128 / BPS_DENOMINATOR as u128) as u64;
+            collector_amount -= cut;
+            payouts.push((destination.clone(), cut));
+        }
+        payouts.push((ctx.accounts.fee_collector_token_account.to_account_info(), collector_amount));
+
+        for (destination, payout) in payouts {
+            if payout == 0 {
+                continue;
+            }
+            pay_from_fee_vault(
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.fee_vault_token_account.to_account_info(),
+                &ctx.accounts.mint,
+                &destination,
+                &ctx.accounts.token_program.to_account_info(),
+                payout,
+            )?;
+
+            emit!(FeesWithdrawn {
+                mint: Some(mint),
+                amount: payout,
+                destination: destination.key(),
+                total_fees_withdrawn: ctx.accounts.global_state.total_fees_withdrawn,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+
+            msg!("Withdrew {} units of {} fees to: {}", payout, mint, destination.key());
+        }
+        Ok(())
+    }
+
+    /// Register a new wager tier with its own fee rate (only authority can call this, or the council
+    /// through an approved AdminChange::AddWagerTier). Tiers start disabled and are enabled, repriced
+    /// or disabled through an AdminChange::WagerTier change.
+    pub fn add_wager_tier(ctx: Context<AddWagerTier>, wager: u64, fee_bps: u16) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require_approved_call(
+            global_state,
+            ctx.accounts.council.as_deref(),
+            ctx.accounts.approved_change.as_deref(),
+            ctx.accounts.proposer.as_ref().map(|proposer| proposer.key()),
+            &ctx.accounts.admin.key(),
+            &AdminChange::AddWagerTier { wager, fee_bps },
+        )?;
+        require!(wager > 0, GameError::InvalidWagerTier);
+        require!(fee_bps <= global_state.max_fee_bps, GameError::FeeAboveCap);
+
+        let tier = &mut ctx.accounts.wager_tier;
+        tier.tier_id = global_state.wager_tier_counter;
+        tier.wager = wager;
+        tier.fee_bps = fee_bps;
+        tier.enabled = false; // Players only see it once an admin change enabling it has gone through the delay
+        tier.bump = ctx.bumps.wager_tier;
+        global_state.wager_tier_counter += 1;
+
+        msg!("Wager tier #{} added (disabled): {} lamports, fee {} bps", tier.tier_id, wager, fee_bps);
+        Ok(())
+    }
+
+    //QUESTION game_id unused variable?
+    pub fn commit_move(ctx: Context<CommitMove>, _game_id: u64, move_hash: [u8; 32]) -> Result<()> {
+        // Not paused: commit and reveal deadlines keep running, so players must always be able to act
+        let game = &mut ctx.accounts.game;
+        let player = ctx.accounts.player.key();
+
+        require!(game.state == GameState::CommitPhase, GameError::InvalidGameState);
+        require!(player == game.player1 || player == game.player2, GameError::NotPlayerInGame);
+        if let Some(deadline) = game.commit_deadline {
+            // Late commits would race a pending claim_commit_timeout
+            require!(Clock::get()?.unix_timestamp <= deadline, GameError::CommitDeadlinePassed);
+        }
 
         // Commit the move for the calling player (profile is always the caller's)
         commit_move_helper(
@@ -307,7 +1596,10 @@ pub mod crypto_pvp {
         // If both players have committed, advance to reveal phase
         if game.player1_move_hash.is_some() && game.player2_move_hash.is_some() {
             game.state = GameState::RevealPhase;
+            let clock = Clock::get()?;
+            game.reveal_deadline = Some(clock.unix_timestamp + REVEAL_TIMEOUT_SECONDS);
             msg!("Both players committed. Game #{} advanced to reveal phase", game.game_id);
+            msg!("Reveal deadline set: {}", game.reveal_deadline.unwrap());
         } else {
             msg!("Player {} committed move in game #{}", player, game.game_id);
             msg!("Waiting for the other player");
@@ -324,46 +1616,198 @@ fn determine_winner(move1: Move, move2: Move) -> Winner {
     }
 }
 
-/// Helper function to process wager with fee collection
+/// Helper function to process wager with fee collection, from the player's wallet, token
+/// account or internal balance (funds_vault present). Returns the lamports accrued in the
+/// fee vault (0 while the fee is escrowed in the game or paid in tokens).
 fn process_wager<'info>(
     player: &AccountInfo<'info>,
-    fee_collector: &AccountInfo<'info>,
+    player_profile: &mut PlayerProfile,
+    fee_vault: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
-    game: &Account<'info, Game>,
-) -> Result<()> {
+    game: &mut Account<'info, Game>,
+    deposit: Option<&TokenDeposit<'info>>,
+    funds_vault: Option<&AccountInfo<'info>>,
+) -> Result<u64> {
     let fee_per_player = game.fee_per_player;
-    let net_per_player = game.net_per_player();
+    let net_per_player = game.net_per_player()?;
     
-    require!(fee_per_player > 0 && net_per_player > 0, GameError::InsufficientFunds);
+    // A zero fee (fee_bps 0) is allowed; only the wager itself must be non-zero
+    require!(net_per_player > 0, GameError::InsufficientFunds);
+
+    if let Some(deposit) = deposit {
+        // SPL wagers: same split as below, out of the player's token account
+        require!(funds_vault.is_none(), GameError::InternalBalanceSolOnly);
+        let escrow = &deposit.escrow.escrow;
+        let surplus = if game.tie_policy == TiePolicy::RefundWithFees {
+            let surplus = transfer_from_player(player, deposit, escrow, fee_per_player + net_per_player)?;
+            msg!("Fee escrowed: {} token units until settlement", fee_per_player);
+            surplus
+        } else {
+            // Rounding surplus on the fee stays in the fee vault
+            transfer_from_player(player, deposit, &deposit.fee_destination, fee_per_player)?;
+            msg!("Fee collected: {} token units to fee vault", fee_per_player);
+            transfer_from_player(player, deposit, escrow, net_per_player)?
+        };
+        // Refunded to the depositor when the escrow closes
+        game.record_token_surplus(player.key, surplus)?;
+        return Ok(0);
+    }
+
+    match funds_vault {
+        Some(funds_vault) => {
+            // available_funds is in lamports; the vault and game are program-owned, so no CPI needed
+            require!(player_profile.available_funds >= game.wager, GameError::InsufficientAvailableFunds);
+            player_profile.available_funds -= game.wager;
+            **funds_vault.try_borrow_mut_lamports()? -= game.wager;
+            **game.to_account_info().try_borrow_mut_lamports()? += game.wager;
+            msg!("Stake taken from available funds, remaining: {}", player_profile.available_funds);
+        }
+        None => {
+            // One system transfer for the whole wager, fee included
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: player.clone(),
+                        to: game.to_account_info(),
+                    },
+                ),
+                game.wager,
+            )?;
+        }
+    }
+
+    if game.tie_policy == TiePolicy::RefundWithFees {
+        // Fee stays in the game until settlement so a tie can return it
+        msg!("Fee escrowed: {} lamports until settlement", fee_per_player);
+        return Ok(0);
+    }
+
+    // Game and fee vault are both program-owned, so the fee moves without another CPI
+    **game.to_account_info().try_borrow_mut_lamports()? -= fee_per_player;
+    **fee_vault.try_borrow_mut_lamports()? += fee_per_player;
+    msg!("Fee collected: {} lamports to fee vault", fee_per_player);
     
-    // Transfer fee to fee collector
-    transfer(
-        CpiContext::new(
-            system_program.clone(),
-            Transfer {
-                from: player.clone(),
-                to: fee_collector.clone(),
-            },
-        ),
-        fee_per_player,
-    )?;
-    msg!("Fee collected: {} lamports to fee collector", fee_per_player);
+    Ok(fee_per_player)
+}
+
+/// Helper function to resolve the fee per player for a wager: a tier's own rate, or the global
+/// limits and rate. Tiers, wager limits and fee bounds are in lamports, so SPL wagers only pick
+/// up the global rate.
+fn wager_fee(
+    global_state: &GlobalState,
+    wager: u64,
+    mint: Option<Pubkey>,
+    wager_tier: Option<&WagerTier>,
+) -> Result<u64> {
+    if mint.is_some() {
+        require!(wager_tier.is_none(), GameError::WagerTierMismatch);
+        require!(wager > 0, GameError::WagerOutOfRange);
+        return fee_per_player(wager, global_state.fee_bps, 0, 0);
+    }
+    let fee_bps = match wager_tier {
+        Some(tier) => {
+            require!(tier.enabled, GameError::WagerTierDisabled);
+            require!(tier.wager == wager, GameError::WagerTierMismatch);
+            // The cap may have been lowered since the tier was priced
+            require!(tier.fee_bps <= global_state.max_fee_bps, GameError::FeeAboveCap);
+            tier.fee_bps
+        }
+        None => {
+            require!(
+                wager >= global_state.min_wager && wager <= global_state.max_wager,
+                GameError::WagerOutOfRange
+            );
+            global_state.fee_bps
+        }
+    };
+    fee_per_player(wager, fee_bps, global_state.min_fee, global_state.max_fee)
+}
+
+/// Helper function to set up a freshly created game account with terms locked from global state
+fn init_game_data(
+    game: &mut Account<Game>,
+    global_state: &GlobalState,
+    player1: Pubkey,
+    wager: u64,
+    fee: u64,
+    series_length: u8,
+    bump: u8,
+) -> Result<()> {
+    game.game_id = global_state.game_counter;
+    game.player1 = player1;
+    game.player2 = Pubkey::default();
+    game.wager = wager;
+    game.series_length = series_length;
+    game.fee_per_player = fee; // Lock in current fee
+    game.no_reveal_penalty = game
+        .net_per_player()?
+        .checked_mul(global_state.no_reveal_penalty_percentage)
+        .ok_or(GameError::MathOverflow)?
+        / 100; // Lock in current penalty
+    game.tie_policy = global_state.tie_policy; // Lock in current tie policy
+    game.state = GameState::WaitingForPlayer; // Player1 has created, waiting for player2 to join
+    game.winner_type = None;
+    game.winner_address = None;
+    game.bump = bump;
+    game.version = GAME_VERSION;
+    Ok(())
+}
+
+/// Helper function to seat player2, take their wager and start the commit phase (used by join_game and accept_rematch).
+/// Returns the lamports accrued in the fee vault.
+fn seat_player2<'info>(
+    game: &mut Account<'info, Game>,
+    player: &AccountInfo<'info>,
+    player2_profile: &mut Account<PlayerProfile>,
+    fee_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    deposit: Option<&TokenDeposit<'info>>,
+    funds_vault: Option<&AccountInfo<'info>>,
+) -> Result<u64> {
+    game.player2 = player.key();
+    game.player2_uses_funds = funds_vault.is_some(); // Stake from available_funds
     
-    // Transfer remaining wager to game account
-    transfer(
-        CpiContext::new(
-            system_program.clone(),
-            Transfer {
-                from: player.clone(),
-                to: game.to_account_info(),
-            },
-        ),
-        net_per_player,
-    )?;
+    // Process wager with fee collection
+    let fee_accrued = process_wager(player, player2_profile, fee_vault, system_program, game, deposit, funds_vault)?;
+
+    player2_profile.active_games += 1;
+    // After both players joined, advance to commit phase
+    game.state = GameState::CommitPhase;
+    let clock = Clock::get()?;
+    game.commit_deadline = Some(clock.unix_timestamp + COMMIT_TIMEOUT_SECONDS);
     
+    //TODO change to event emition?
+    msg!("Player2 joined game #{}: {} (fee: {})", game.game_id, player.key(), game.fee_per_player);
+    msg!("Game advanced to commit phase, commit deadline: {}", game.commit_deadline.unwrap());
+    Ok(fee_accrued)
+}
+
+/// Helper function to clear commitments and restart the commit phase for the next round of a series
+fn start_next_round(game: &mut Account<Game>) -> Result<()> {
+    game.player1_move_hash = None;
+    game.player2_move_hash = None;
+    game.player1_move = None;
+    game.player2_move = None;
+    game.reveal_deadline = None;
+    game.state = GameState::CommitPhase;
+    let clock = Clock::get()?;
+    game.commit_deadline = Some(clock.unix_timestamp + COMMIT_TIMEOUT_SECONDS);
+
+    msg!("Game #{} advanced to round {}, commit deadline: {}",
+         game.game_id, game.current_round(), game.commit_deadline.unwrap());
     Ok(())
 }
 
+/// Helper function to bind a join secret to the joining player (same hash as move commitments)
+fn join_commitment(secret: &[u8; 32], player: &Pubkey) -> [u8; 32] {
+    // Format: [32_secret_bytes] + [32_pubkey_bytes]
+    let mut data = Vec::with_capacity(64);
+    data.extend_from_slice(secret);
+    data.extend_from_slice(player.as_ref());
+    hash(&data).to_bytes()
+}
+
 /// Helper function to commit a player's move (used by create_game and join_game)
 fn commit_move_helper(
     game: &mut Account<Game>,
@@ -386,64 +1830,326 @@ fn commit_move_helper(
         return Err(GameError::NotPlayerInGame.into());
     }
 
-    // Increment total_games_played when player participates in a game
-    player_profile.total_games_played += 1;
+    // Increment total_games_played when player participates in a game (once per series)
+    if game.is_first_round() {
+        player_profile.total_games_played += 1;
+    }
 
     msg!("Move committed by player: {} in game #{}", player, game.game_id);
     Ok(())
 }
 
-/// Helper function to initialize a player profile with default name
-fn initialize_player_profile_if_needed(
-    player_profile: &mut Account<PlayerProfile>,
-    player: &Pubkey,
-    bump: u8,
-) -> Result<()> {
-    // Check if this is a newly created account (all fields are zero-initialized)
-    if player_profile.player == Pubkey::default() {
-        let clock = Clock::get()?;
-        
-        // Generate default name using last 4 chars of pubkey
-        let player_str = player.to_string();
-        let default_name = format!("Player{}", &player_str[player_str.len()-4..]);
-        
-        player_profile.player = *player;
-        player_profile.name = default_name;
-        player_profile.created_at = clock.unix_timestamp;
-        player_profile.bump = bump;
-        // All other fields default to 0
-        
-        msg!("New player profile created: {} with default name: {}", player, player_profile.name);
+/// Helper function to check who may make admin calls: the authority, or a council member once the
+/// admin council is enabled
+fn require_admin(global_state: &GlobalState, council: Option<&AdminCouncil>, admin: &Pubkey) -> Result<()> {
+    if global_state.council_enabled {
+        let council = council.ok_or(GameError::CouncilRequired)?;
+        require!(council.members.contains(admin), GameError::NotCouncilMember);
+    } else {
+        require!(*admin == global_state.authority, GameError::Unauthorized);
     }
     Ok(())
 }
 
-/// Helper function to update player stats when a game finishes
-fn update_player_stats(
-    player1_profile: &mut Account<PlayerProfile>,
-    player2_profile: &mut Account<PlayerProfile>,
-    winner_type: Winner,
-    game: &Game,
+/// Helper function for calls the council can't share out among members: the authority alone,
+/// and only until the admin council is enabled
+fn require_authority(global_state: &GlobalState, admin: &Pubkey) -> Result<()> {
+    require!(!global_state.council_enabled, GameError::CouncilGoverned);
+    require!(*admin == global_state.authority, GameError::Unauthorized);
+    Ok(())
+}
+
+/// Helper function for admin calls the council makes together: the authority alone until the
+/// council is enabled, then a member holding a queued change that approves this exact call
+fn require_approved_call(
+    global_state: &GlobalState,
+    council: Option<&AdminCouncil>,
+    approved_change: Option<&PendingAdminChange>,
+    proposer: Option<Pubkey>,
+    admin: &Pubkey,
+    call: &AdminChange,
 ) -> Result<()> {
-    let profit = game.net_per_player(); // Winner's profit = opponent's net contribution
-    
-    // Update wins/losses/ties and game completion stats based on outcome
-    match winner_type {
+    if !global_state.council_enabled {
+        require!(approved_change.is_none(), GameError::ApprovedChangeMismatch);
+        return require_authority(global_state, admin);
+    }
+    let council = council.ok_or(GameError::CouncilRequired)?;
+    require!(council.members.contains(admin), GameError::NotCouncilMember);
+    let approved_change = approved_change.ok_or(GameError::ApprovedChangeRequired)?;
+    require!(approved_change.change == *call, GameError::ApprovedChangeMismatch);
+    require!(proposer == Some(approved_change.proposed_by), GameError::ProposerRequired);
+    require!(
+        Clock::get()?.unix_timestamp >= approved_change.effective_at,
+        GameError::AdminChangeNotReady
+    );
+    require!(
+        council.approvals(approved_change) >= council.threshold,
+        GameError::NotEnoughApprovals
+    );
+    Ok(())
+}
+
+/// Helper function to validate council membership: distinct members and a reachable threshold
+fn validate_council(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(members.len() <= MAX_COUNCIL_MEMBERS, GameError::InvalidCouncil);
+    require!(threshold > 0 && threshold as usize <= members.len(), GameError::InvalidCouncil);
+    for (i, member) in members.iter().enumerate() {
+        require!(!members[..i].contains(member), GameError::InvalidCouncil);
+    }
+    Ok(())
+}
+
+/// Helper function to validate and apply a timelocked admin change to the global state
+/// (and the admin council, for membership changes)
+fn apply_admin_change(
+    global_state: &mut GlobalState,
+    council: Option<&mut AdminCouncil>,
+    wager_tier: Option<&mut WagerTier>,
+    change: &AdminChange,
+) -> Result<()> {
+    match change {
+        AdminChange::FeeBps { fee_bps } => {
+            require!(*fee_bps <= global_state.max_fee_bps, GameError::FeeAboveCap);
+            let old_fee_bps = global_state.fee_bps;
+            global_state.fee_bps = *fee_bps;
+            msg!("Fee updated from {} bps to {} bps", old_fee_bps, fee_bps);
+        }
+        AdminChange::FeeLimits { max_fee_bps, min_fee, max_fee } => {
+            // The cap can't reach 100% or sit below the fee currently charged
+            require!(
+                (*max_fee_bps as u64) < BPS_DENOMINATOR && global_state.fee_bps <= *max_fee_bps,
+                GameError::InvalidFeeLimits
+            );
+            require!(*max_fee == 0 || min_fee <= max_fee, GameError::InvalidFeeLimits);
+            global_state.max_fee_bps = *max_fee_bps;
+            global_state.min_fee = *min_fee;
+            global_state.max_fee = *max_fee;
+            msg!("Fee limits updated: cap {} bps, min {} lamports, max {} lamports (0 = no bound)",
+                 max_fee_bps, min_fee, max_fee);
+        }
+        AdminChange::FeeCollector { fee_collector } => {
+            let old_collector = global_state.fee_collector;
+            global_state.fee_collector = *fee_collector;
+            msg!("Fee collector updated from {} to {}", old_collector, fee_collector);
+        }
+        AdminChange::FeeSplit { entries } => {
+            require!(entries.len() <= MAX_FEE_SHARES, GameError::InvalidFeeSplit);
+            let mut total_bps: u64 = 0;
+            for (i, entry) in entries.iter().enumerate() {
+                require!(entry.bps > 0, GameError::InvalidFeeSplit);
+                require!(
+                    entries[..i].iter().all(|other| other.recipient != entry.recipient),
+                    GameError::InvalidFeeSplit
+                );
+                total_bps += entry.bps as u64;
+            }
+            require!(total_bps <= BPS_DENOMINATOR, GameError::InvalidFeeSplit);
+
+            // Recipients keep what they accrued; dropping one with an unclaimed balance would strand it
+            let mut fee_shares = Vec::with_capacity(entries.len());
+            for entry in entries {
+                let claimable = global_state
+                    .fee_shares
+                    .iter()
+                    .find(|share| share.recipient == entry.recipient)
+                    .map_or(0, |share| share.claimable);
+                fee_shares.push(FeeShare { recipient: entry.recipient, bps: entry.bps, claimable });
+            }
+            require!(
+                global_state.fee_shares.iter().all(|share| share.claimable == 0
+                    || entries.iter().any(|entry| entry.recipient == share.recipient)),
+                GameError::FeeShareNotClaimed
+            );
+            global_state.fee_shares = fee_shares;
+
+            for share in &global_state.fee_shares {
+                msg!("Fee split: {} bps to {}", share.bps, share.recipient);
+            }
+            msg!("Fee split updated ({} bps shared)", total_bps);
+        }
+        AdminChange::ReferralBps { referral_bps } => {
+            require!(*referral_bps as u64 <= BPS_DENOMINATOR, GameError::InvalidReferralShare);
+            let old_referral_bps = global_state.referral_bps;
+            global_state.referral_bps = *referral_bps;
+            msg!("Referral share updated from {} bps to {} bps", old_referral_bps, referral_bps);
+        }
+        AdminChange::ChangeDelay { delay_seconds } => {
+            require!(
+                (0..=MAX_ADMIN_CHANGE_DELAY).contains(delay_seconds),
+                GameError::InvalidAdminChangeDelay
+            );
+            let old_delay = global_state.admin_change_delay;
+            global_state.admin_change_delay = *delay_seconds;
+            msg!("Admin change delay updated from {}s to {}s", old_delay, delay_seconds);
+        }
+        AdminChange::Council { members, threshold } => {
+            let council = council.ok_or(GameError::CouncilRequired)?;
+            validate_council(members, *threshold)?;
+            council.members = members.clone();
+            council.threshold = *threshold;
+            msg!("Admin council updated: {} members, threshold {}", members.len(), threshold);
+        }
+        AdminChange::WagerTier { tier_id, wager, fee_bps, enabled } => {
+            let tier = wager_tier.ok_or(GameError::WagerTierRequired)?;
+            require!(tier.tier_id == *tier_id, GameError::WagerTierRequired);
+            require!(*wager > 0, GameError::InvalidWagerTier);
+            require!(*fee_bps <= global_state.max_fee_bps, GameError::FeeAboveCap);
+            // Games already created keep their locked terms
+            msg!("Wager tier #{} updated from {} lamports, fee {} bps, enabled {} to {} lamports, fee {} bps, enabled {}",
+                 tier_id, tier.wager, tier.fee_bps, tier.enabled, wager, fee_bps, enabled);
+            tier.wager = *wager;
+            tier.fee_bps = *fee_bps;
+            tier.enabled = *enabled;
+        }
+        AdminChange::NoRevealPenalty { percentage } => {
+            require!(*percentage <= 100, GameError::InvalidPenaltyPercentage);
+            let old_percentage = global_state.no_reveal_penalty_percentage;
+            global_state.no_reveal_penalty_percentage = *percentage;
+            msg!("No-reveal penalty updated from {}% to {}%", old_percentage, percentage);
+        }
+        AdminChange::Authority { new_authority: Some(new_authority) } => {
+            require!(*new_authority != global_state.authority, GameError::InvalidAuthorityTransfer);
+            // The proposed authority still has to call accept_authority itself
+            global_state.pending_authority = Some(*new_authority);
+            msg!("Authority transfer to {} proposed", new_authority);
+        }
+        AdminChange::Authority { new_authority: None } => {
+            let pending_authority = global_state.pending_authority.take().ok_or(GameError::NoPendingAuthority)?;
+            msg!("Authority transfer to {} cancelled", pending_authority);
+        }
+        AdminChange::WagerLimits { min_wager, max_wager } => {
+            require!(*min_wager > 0 && min_wager <= max_wager, GameError::InvalidWagerLimits);
+            msg!("Wager limits updated from {}-{} to {}-{} lamports",
+                 global_state.min_wager, global_state.max_wager, min_wager, max_wager);
+            global_state.min_wager = *min_wager;
+            global_state.max_wager = *max_wager;
+        }
+        AdminChange::TiePolicy { tie_policy } => {
+            // Games already created keep the policy they were created with
+            msg!("Tie policy updated from {:?} to {:?}", global_state.tie_policy, tie_policy);
+            global_state.tie_policy = *tie_policy;
+        }
+        AdminChange::Pause { new_games_paused, paused } => {
+            global_state.new_games_paused = *new_games_paused;
+            global_state.paused = *paused;
+            msg!("Pause updated (new games: {}, everything: {})", new_games_paused, paused);
+        }
+        AdminChange::WithdrawFees { .. }
+        | AdminChange::WithdrawTokenFees { .. }
+        | AdminChange::AddWagerTier { .. } => {
+            return err!(GameError::ApprovedCallNotExecutable);
+        }
+    }
+    Ok(())
+}
+
+/// Helper function to initialize a player profile with default name. A new profile takes its
+/// referrer from the player's referral link; the link is written by the first profile created
+/// with a referral account and never changes afterwards.
+fn initialize_player_profile_if_needed(
+    player_profile: &mut Account<PlayerProfile>,
+    player: &Pubkey,
+    bump: u8,
+    referral: Option<&mut Account<ReferralAccount>>,
+    referral_link: Option<&mut Account<ReferralLink>>,
+    referral_link_bump: Option<u8>,
+) -> Result<()> {
+    // Check if this is a newly created account (all fields are zero-initialized)
+    if player_profile.player == Pubkey::default() {
+        let clock = Clock::get()?;
+        
+        // Generate default name using last 4 chars of pubkey
+        let player_str = player.to_string();
+        let default_name = format!("Player{}", &player_str[player_str.len()-4..]);
+        
+        player_profile.player = *player;
+        player_profile.name = default_name;
+        player_profile.created_at = clock.unix_timestamp;
+        player_profile.bump = bump;
+        player_profile.version = PLAYER_PROFILE_VERSION;
+        // All other fields default to 0
+        
+        msg!("New player profile created: {} with default name: {}", player, player_profile.name);
+
+        // Always checked, so a re-created profile can't drop a referrer recorded earlier
+        let link = referral_link.ok_or(GameError::ReferralLinkRequired)?;
+        if link.player == Pubkey::default() {
+            if let Some(referral) = referral {
+                // First referral for this player: recorded for good
+                require!(referral.referrer != *player, GameError::SelfReferral);
+                link.player = *player;
+                link.referrer = referral.referrer;
+                link.bump = referral_link_bump.ok_or(GameError::ReferralLinkRequired)?;
+                referral.referral_count += 1;
+            }
+        } else if let Some(referral) = referral {
+            require_keys_eq!(referral.referrer, link.referrer, GameError::ReferrerAlreadySet);
+        }
+        if link.player != Pubkey::default() {
+            player_profile.referrer = Some(link.referrer);
+            msg!("Referred by: {}", link.referrer);
+        }
+    }
+    Ok(())
+}
+
+/// Helper function to set up the player's stats for an SPL game's mint on first use.
+/// Returns the stats for SPL games, None for SOL games.
+fn init_player_mint_stats_if_needed<'a>(
+    mint_stats: Option<&'a mut Account<PlayerMintStats>>,
+    player: &Pubkey,
+    game: &Game,
+    bump: Option<u8>,
+) -> Result<Option<&'a mut PlayerMintStats>> {
+    let Some(mint) = game.mint else {
+        return Ok(None);
+    };
+    let mint_stats = mint_stats.ok_or(GameError::MintStatsRequired)?;
+    // Newly created accounts are zero-initialized
+    if mint_stats.player == Pubkey::default() {
+        mint_stats.player = *player;
+        mint_stats.mint = mint;
+        mint_stats.bump = bump.ok_or(GameError::MintStatsRequired)?;
+    }
+    Ok(Some(mint_stats))
+}
+
+/// Helper function to update player stats when a game finishes
+fn update_player_stats(
+    player1_profile: &mut Account<PlayerProfile>,
+    player2_profile: &mut Account<PlayerProfile>,
+    player1_mint_stats: Option<&mut PlayerMintStats>,
+    player2_mint_stats: Option<&mut PlayerMintStats>,
+    winner_type: Winner,
+    game: &Game,
+) -> Result<()> {
+    let profit = game.net_per_player()?; // Winner's profit = opponent's net contribution
+    
+    // Tied rounds that were replayed (Replay policy or series) count as ties but not as games
+    player1_profile.ties += game.round_ties as u32;
+    player2_profile.ties += game.round_ties as u32;
+
+    // Game is no longer in play for either player
+    player1_profile.active_games = player1_profile.active_games.checked_sub(1).ok_or(GameError::MathOverflow)?;
+    player2_profile.active_games = player2_profile.active_games.checked_sub(1).ok_or(GameError::MathOverflow)?;
+
+    // Update wins/losses/ties and game completion stats based on outcome
+    match winner_type {
         Winner::Player1 => {
             player1_profile.total_games_completed += 1;
             player2_profile.total_games_completed += 1;
             player1_profile.wins += 1;
             player2_profile.losses += 1;
-            player1_profile.total_won += profit; // profit from opponent
-            player2_profile.total_lost += game.wager.to_lamports();
+            player1_profile.record_won(player1_mint_stats, game, profit)?; // profit from opponent
+            player2_profile.record_lost(player2_mint_stats, game, game.wager)?;
         }
         Winner::Player2 => {
             player1_profile.total_games_completed += 1;
             player2_profile.total_games_completed += 1;
             player1_profile.losses += 1;
             player2_profile.wins += 1;
-            player2_profile.total_won += profit; // profit from opponent
-            player1_profile.total_lost += game.wager.to_lamports();
+            player2_profile.record_won(player2_mint_stats, game, profit)?; // profit from opponent
+            player1_profile.record_lost(player1_mint_stats, game, game.wager)?;
         }
         Winner::Tie => {
             player1_profile.total_games_completed += 1;
@@ -458,8 +2164,8 @@ fn update_player_stats(
             player2_profile.total_games_forfeited += 1;
             player1_profile.wins += 1;
             player2_profile.losses += 1;
-            player1_profile.total_won += profit; // profit from opponent
-            player2_profile.total_lost += game.wager.to_lamports();
+            player1_profile.record_won(player1_mint_stats, game, profit)?; // profit from opponent
+            player2_profile.record_lost(player2_mint_stats, game, game.wager)?;
         }
         Winner::Player2OpponentForfeit => {
             // Player2 completed, Player1 forfeited
@@ -467,138 +2173,1205 @@ fn update_player_stats(
             player2_profile.total_games_completed += 1;
             player1_profile.losses += 1;
             player2_profile.wins += 1;
-            player2_profile.total_won += profit; // profit from opponent
-            player1_profile.total_lost += game.wager.to_lamports();
+            player2_profile.record_won(player2_mint_stats, game, profit)?; // profit from opponent
+            player1_profile.record_lost(player1_mint_stats, game, game.wager)?;
+        }
+        Winner::Player1OpponentNoCommit => {
+            // Player1 committed, Player2 forfeited by never committing
+            player1_profile.total_games_completed += 1;
+            player2_profile.total_games_forfeited += 1;
+            player1_profile.wins += 1;
+            player2_profile.losses += 1;
+            player1_profile.record_won(player1_mint_stats, game, profit)?; // profit from opponent
+            player2_profile.record_lost(player2_mint_stats, game, game.wager)?;
+        }
+        Winner::Player2OpponentNoCommit => {
+            // Player2 committed, Player1 forfeited by never committing
+            player1_profile.total_games_forfeited += 1;
+            player2_profile.total_games_completed += 1;
+            player1_profile.losses += 1;
+            player2_profile.wins += 1;
+            player2_profile.record_won(player2_mint_stats, game, profit)?; // profit from opponent
+            player1_profile.record_lost(player1_mint_stats, game, game.wager)?;
+        }
+        Winner::NoCommitRefund => {
+            // Neither player committed, both forfeit and get their net wager back
+            player1_profile.total_games_forfeited += 1;
+            player2_profile.total_games_forfeited += 1;
+        }
+        Winner::NoRevealRefund => {
+            // Neither player revealed, both forfeit and lose the penalty
+            player1_profile.total_games_forfeited += 1;
+            player2_profile.total_games_forfeited += 1;
+            player1_profile.record_lost(player1_mint_stats, game, game.no_reveal_penalty)?;
+            player2_profile.record_lost(player2_mint_stats, game, game.no_reveal_penalty)?;
         }
     }
     
     Ok(())
 }
 
-/// Helper function to handle game payouts - transfers SOL from game account to winners
-fn payout_winner(
-    game: &mut Account<Game>,
-    player1_info: &AccountInfo,
-    player2_info: &AccountInfo,
-    winner_type: Winner,
-) -> Result<()> {
-    let total_pot = game.total_pot();
-    let net_per_player = game.net_per_player();
-    
-    // Safety check: ensure game account has the expected funds
-    let game_balance = game.to_account_info().lamports();
-    require!(game_balance >= total_pot, GameError::InsufficientFunds);
-    
-    match winner_type {
-        Winner::Player1 | Winner::Player1OpponentForfeit => {
-            // Transfer entire pot to player1
-            **game.to_account_info().try_borrow_mut_lamports()? -= total_pot;
-            **player1_info.try_borrow_mut_lamports()? += total_pot;
-            
-            msg!("Payout: {} lamports to winner Player1: {}", total_pot, game.player1);
-        }
-        Winner::Player2 | Winner::Player2OpponentForfeit => {
-            // Transfer entire pot to player2  
-            **game.to_account_info().try_borrow_mut_lamports()? -= total_pot;
-            **player2_info.try_borrow_mut_lamports()? += total_pot;
-            
-            msg!("Payout: {} lamports to winner Player2: {}", total_pot, game.player2);
-        }
-        Winner::Tie => {
-            // Transfer original net contribution to each player
-            **game.to_account_info().try_borrow_mut_lamports()? -= total_pot;
-            **player1_info.try_borrow_mut_lamports()? += net_per_player;
-            **player2_info.try_borrow_mut_lamports()? += net_per_player;
-            
-            msg!("Tie payout: {} lamports to each player", net_per_player);
-        }
-    }
-    
-    Ok(())
+/// Helper function to record a game's outcome for indexers before the account can be closed
+fn emit_game_settled(game: &Game) -> Result<()> {
+    let clock = Clock::get()?;
+    emit!(GameSettled {
+        game_id: game.game_id,
+        player1: game.player1,
+        player2: game.player2,
+        wager: game.wager,
+        mint: game.mint,
+        fee_per_player: game.fee_per_player,
+        player1_move: game.player1_move,
+        player2_move: game.player2_move,
+        winner_type: game.winner_type.ok_or(GameError::GameNotSettled)?,
+        winner_address: game.winner_address,
+        series_length: game.series_length,
+        round_results: game.round_results.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Helper function to pay out a finished game, then credit available_funds for players
+/// who staked from it (their payees are the funds vault)
+fn settle_payouts<'info>(
+    game: &mut Account<'info, Game>,
+    payees: &Payees<'info>,
+    player1_profile: &mut PlayerProfile,
+    player2_profile: &mut PlayerProfile,
+    referrals: &mut SettlementReferralAccounts<'info>,
+    global_state: &mut GlobalState,
+    winner_type: Winner,
+) -> Result<()> {
+    let (player1_payout, player2_payout) = payout_winner(game, payees, winner_type)?;
+    let (player1_fee_refund, player2_fee_refund) =
+        settle_escrowed_fees(game, payees, player1_profile, player2_profile, referrals, global_state, winner_type)?;
+
+    if game.player1_uses_funds {
+        player1_profile.available_funds += player1_payout + player1_fee_refund;
+    }
+    if game.player2_uses_funds {
+        player2_profile.available_funds += player2_payout + player2_fee_refund;
+    }
+    Ok(())
+}
+
+/// Helper function to pick where a player's SOL payout goes: their wallet, or the funds vault
+/// when they staked from available_funds
+fn payout_account<'info>(
+    uses_funds: bool,
+    wallet: &AccountInfo<'info>,
+    funds_vault: &Option<Account<'info, FundsVault>>,
+) -> Result<AccountInfo<'info>> {
+    if !uses_funds {
+        return Ok(wallet.clone());
+    }
+    Ok(funds_vault.as_ref().ok_or(GameError::FundsVaultRequired)?.to_account_info())
+}
+
+/// Helper function to release fees held by the game (RefundWithFees tie policy): ties get
+/// their fees back, every other outcome forwards them to the fee vault.
+/// Returns the fee refunded to (player1, player2).
+fn settle_escrowed_fees<'info>(
+    game: &mut Account<'info, Game>,
+    payees: &Payees<'info>,
+    player1_profile: &PlayerProfile,
+    player2_profile: &PlayerProfile,
+    referrals: &mut SettlementReferralAccounts<'info>,
+    global_state: &mut GlobalState,
+    winner_type: Winner,
+) -> Result<(u64, u64)> {
+    if game.tie_policy != TiePolicy::RefundWithFees {
+        return Ok((0, 0));
+    }
+
+    let fee_per_player = game.fee_per_player;
+    let total_fee = fee_per_player.checked_mul(2).ok_or(GameError::MathOverflow)?;
+    let token = payees.token.as_ref();
+    if winner_type == Winner::Tie {
+        pay_from_escrow(game, token, &payees.player1, fee_per_player)?;
+        pay_from_escrow(game, token, &payees.player2, fee_per_player)?;
+        msg!("Tie fee refund: {} to each player", fee_per_player);
+        Ok((fee_per_player, fee_per_player))
+    } else {
+        pay_from_escrow(game, token, &payees.fee_vault, total_fee)?;
+        if token.is_none() {
+            referrals.accrue_fees(global_state, player1_profile, player2_profile, fee_per_player)?;
+        }
+        msg!("Fee collected: {} to fee vault", total_fee);
+        Ok((0, 0))
+    }
+}
+
+/// Helper function to handle game payouts - transfers SOL from the game account (or tokens
+/// from its escrow) to winners. Returns the amount paid to (player1, player2).
+fn payout_winner<'info>(
+    game: &mut Account<'info, Game>,
+    payees: &Payees<'info>,
+    winner_type: Winner,
+) -> Result<(u64, u64)> {
+    let total_pot = game.total_pot()?;
+    let net_per_player = game.net_per_player()?;
+    let token = payees.token.as_ref();
+    
+    // Safety check: ensure game account has the expected funds
+    let game_balance = escrow_balance(game, token)?;
+    require!(game_balance >= total_pot, GameError::InsufficientFunds);
+    
+    let payouts = match winner_type {
+        Winner::Player1 | Winner::Player1OpponentForfeit | Winner::Player1OpponentNoCommit => {
+            // Transfer entire pot to player1
+            pay_from_escrow(game, token, &payees.player1, total_pot)?;
+            
+            msg!("Payout: {} to winner Player1: {}", total_pot, game.player1);
+            (total_pot, 0)
+        }
+        Winner::Player2 | Winner::Player2OpponentForfeit | Winner::Player2OpponentNoCommit => {
+            // Transfer entire pot to player2  
+            pay_from_escrow(game, token, &payees.player2, total_pot)?;
+            
+            msg!("Payout: {} to winner Player2: {}", total_pot, game.player2);
+            (0, total_pot)
+        }
+        Winner::Tie | Winner::NoCommitRefund => {
+            // Transfer original net contribution to each player
+            pay_from_escrow(game, token, &payees.player1, net_per_player)?;
+            pay_from_escrow(game, token, &payees.player2, net_per_player)?;
+            
+            msg!("Refund payout: {} to each player", net_per_player);
+            (net_per_player, net_per_player)
+        }
+        Winner::NoRevealRefund => {
+            // Refund net contribution minus penalty; caller moves the penalty out
+            let refund = net_per_player.checked_sub(game.no_reveal_penalty).ok_or(GameError::MathOverflow)?;
+            pay_from_escrow(game, token, &payees.player1, refund)?;
+            pay_from_escrow(game, token, &payees.player2, refund)?;
+            
+            msg!("No-reveal refund: {} to each player", refund);
+            (refund, refund)
+        }
+    };
+    
+    Ok(payouts)
+}
+
+#[derive(Accounts)]
+pub struct InitializeGlobalState<'info> {
+    #[account(
+        init, //Guarantees only-once execution
+        payer = authority,
+        space = 8 + GlobalState::INIT_SPACE,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(mut)] // Must be mut because it's the payer - SOL is deducted for account creation
+    pub authority: Signer<'info>,
+    // Only the upgrade authority can initialize, so nobody can front-run a fresh deploy
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ GameError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    // TODO play around with upgrading contracts later
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    /// CHECK: Still in the deployed layout, so it is checked and deserialized by hand
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: UncheckedAccount<'info>,
+    #[account(mut)] // Pays the rent for the grown account
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePlayerProfile<'info> {
+    /// CHECK: Possibly still in the deployed layout, so it is checked and deserialized by hand
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+    /// CHECK: Owner of the profile, only used for its address
+    pub player: UncheckedAccount<'info>,
+    #[account(mut)] // Pays the rent for the grown account
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct MigrateGame<'info> {
+    /// CHECK: Still in the deployed layout, so it is checked and deserialized by hand
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub player1_profile: Option<Account<'info, PlayerProfile>>, // Required unless the game is finished
+    #[account(mut)]
+    pub player2_profile: Option<Account<'info, PlayerProfile>>, // Required once player2 joined, unless the game is finished
+    #[account(mut)] // Pays the rent for the grown account
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitFeeVault<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        init, // Fails if the vault already exists
+        payer = authority,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateGame<'info> {
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", global_state.game_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(mut)] // Must be mut because it's the payer
+    pub player: Signer<'info>,
+    #[account(
+        init_if_needed,  // ← Creates profile if it doesn't exist
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"referral", referral_account.referrer.as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>, // Required when the player has a referrer
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralLink::INIT_SPACE,
+        seeds = [b"referral_link", player.key().as_ref()],
+        bump
+    )]
+    pub referral_link: Option<Account<'info, ReferralLink>>, // Required when the profile is new: records or supplies its referrer
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        seeds = [b"wager_tier", wager_tier.tier_id.to_le_bytes().as_ref()],
+        bump = wager_tier.bump
+    )]
+    pub wager_tier: Option<Account<'info, WagerTier>>, // None = free wager within global limits
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Present = stake from available_funds
+    pub token: WagerTokenAccounts<'info>, // SPL wager accounts (None = SOL wager)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerMintStats::INIT_SPACE,
+        seeds = [b"player_mint_stats", player.key().as_ref(), token.mint_key().as_ref()],
+        bump
+    )]
+    pub player_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required for SPL wagers
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct JoinGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(mut)] // Must be mut because it's the payer
+    pub player: Signer<'info>,
+    #[account(
+        init_if_needed,  // ← Creates profile if it doesn't exist
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"referral", referral_account.referrer.as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>, // Required when the player has a referrer
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralLink::INIT_SPACE,
+        seeds = [b"referral_link", player.key().as_ref()],
+        bump
+    )]
+    pub referral_link: Option<Account<'info, ReferralLink>>, // Required when the profile is new: records or supplies its referrer
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"join_ticket", game_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump = join_ticket.bump,
+        close = player // Only needed for password-protected games
+    )]
+    pub join_ticket: Option<Account<'info, JoinTicket>>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Present = stake from available_funds
+    pub token: WagerTokenAccounts<'info>, // SPL wager accounts (None = SOL wager)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerMintStats::INIT_SPACE,
+        seeds = [b"player_mint_stats", player.key().as_ref(), token.mint_key().as_ref()],
+        bump
+    )]
+    pub player_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required for SPL wagers
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(previous_game_id: u64)]
+pub struct RequestRematch<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", previous_game_id.to_le_bytes().as_ref()],
+        bump = previous_game.bump
+    )]
+    pub previous_game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", global_state.game_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(mut)] // Must be mut because it's the payer
+    pub player: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"referral", referral_account.referrer.as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>, // Required when the player has a referrer
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralLink::INIT_SPACE,
+        seeds = [b"referral_link", player.key().as_ref()],
+        bump
+    )]
+    pub referral_link: Option<Account<'info, ReferralLink>>, // Required when the profile is new: records or supplies its referrer
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        seeds = [b"wager_tier", wager_tier.tier_id.to_le_bytes().as_ref()],
+        bump = wager_tier.bump
+    )]
+    pub wager_tier: Option<Account<'info, WagerTier>>, // None = free wager within global limits
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Present = stake from available_funds
+    pub token: WagerTokenAccounts<'info>, // SPL wager accounts (None = SOL wager)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerMintStats::INIT_SPACE,
+        seeds = [b"player_mint_stats", player.key().as_ref(), token.mint_key().as_ref()],
+        bump
+    )]
+    pub player_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required for SPL wagers
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct AcceptRematch<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(mut)] // Must be mut because it's the payer
+    pub player: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"referral", referral_account.referrer.as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>, // Required when the player has a referrer
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralLink::INIT_SPACE,
+        seeds = [b"referral_link", player.key().as_ref()],
+        bump
+    )]
+    pub referral_link: Option<Account<'info, ReferralLink>>, // Required when the profile is new: records or supplies its referrer
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Present = stake from available_funds
+    pub token: WagerTokenAccounts<'info>, // SPL wager accounts (None = SOL wager)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerMintStats::INIT_SPACE,
+        seeds = [b"player_mint_stats", player.key().as_ref(), token.mint_key().as_ref()],
+        bump
+    )]
+    pub player_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required for SPL wagers
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct RequestJoin<'info> {
+    #[account(
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + JoinTicket::INIT_SPACE,
+        seeds = [b"join_ticket", game_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub join_ticket: Account<'info, JoinTicket>,
+    #[account(mut)] // Must be mut because it's the payer
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseJoinTicket<'info> {
+    #[account(
+        mut,
+        seeds = [b"join_ticket", game_id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump = join_ticket.bump,
+        close = player
+    )]
+    pub join_ticket: Account<'info, JoinTicket>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct OpenGameToPublic<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CancelGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        close = player // Refunds escrowed wager + rent to player1
+    )]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player1_profile.bump
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if player1 staked from available_funds
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+    /// CHECK: The previous game's PDA, deserialized in the handler since it may have been closed
+    #[account(
+        mut,
+        seeds = [b"game", game.previous_game_id.unwrap_or_default().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub previous_game: Option<UncheckedAccount<'info>>, // Required for rematch games: clears the rematch link so the pair can rematch again
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        close = player1 // Rent goes back to player1, who paid it in create_game
+    )]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+    /// CHECK: Player1 account receiving the rent
+    #[account(mut, address = game.player1)]
+    pub player1: AccountInfo<'info>,
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct RevealMove<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"player_profile", game.player1.as_ref()],
+        bump = player1_profile.bump
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"player_profile", game.player2.as_ref()],
+        bump = player2_profile.bump
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// CHECK: Player1 account for payout
+    #[account(mut, address = game.player1)]
+    pub player1: AccountInfo<'info>,
+    /// CHECK: Player2 account for payout  
+    #[account(mut, address = game.player2)]
+    pub player2: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if either player staked from available_funds
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    pub referrals: SettlementReferralAccounts<'info>, // Referrers credited with escrowed SOL fees
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlayerName<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.version == PLAYER_PROFILE_VERSION @ GameError::ProfileNotMigrated
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    pub player: Signer<'info>, 
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayerProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.version == PLAYER_PROFILE_VERSION @ GameError::ProfileNotMigrated,
+        close = player
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if available_funds is not zero
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+}
+
+#[derive(Accounts)]
+pub struct DepositFunds<'info> {
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    #[account(
+        init_if_needed, // First depositor pays the vault's rent
+        payer = player,
+        space = 8 + FundsVault::INIT_SPACE,
+        seeds = [b"funds_vault"],
+        bump
+    )]
+    pub funds_vault: Account<'info, FundsVault>,
+    #[account(
+        mut,
+        seeds = [b"referral", referral_account.referrer.as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Account<'info, ReferralAccount>>, // Required when the player has a referrer
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralLink::INIT_SPACE,
+        seeds = [b"referral_link", player.key().as_ref()],
+        bump
+    )]
+    pub referral_link: Option<Account<'info, ReferralLink>>, // Required when the profile is new: records or supplies its referrer
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.version == PLAYER_PROFILE_VERSION @ GameError::ProfileNotMigrated
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Account<'info, FundsVault>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>, // Read for the pause flags
+}
+
+#[derive(Accounts)]
+pub struct AddWagerTier<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + WagerTier::INIT_SPACE,
+        seeds = [b"wager_tier", global_state.wager_tier_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub wager_tier: Account<'info, WagerTier>,
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    #[account(
+        mut,
+        seeds = [b"admin_change", approved_change.change_id.to_le_bytes().as_ref()],
+        bump = approved_change.bump,
+        close = proposer
+    )]
+    pub approved_change: Option<Account<'info, PendingAdminChange>>, // Required once the council is enabled; used up
+    #[account(mut)]
+    pub proposer: Option<SystemAccount<'info>>, // Paid the approved change's rent, gets it back
+    #[account(mut)] // Must be mut because it's the payer
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    #[account(
+        mut,
+        seeds = [b"admin_change", approved_change.change_id.to_le_bytes().as_ref()],
+        bump = approved_change.bump,
+        close = proposer
+    )]
+    pub approved_change: Option<Account<'info, PendingAdminChange>>, // Required once the council is enabled; used up
+    #[account(mut)]
+    pub proposer: Option<SystemAccount<'info>>, // Paid the approved change's rent, gets it back
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
+    /// CHECK: Fee collector account - receives withdrawn fees
+    #[account(mut, address = global_state.fee_collector)]
+    pub fee_collector: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeGlobalState<'info> {
+pub struct QueueAdminChange<'info> {
     #[account(
-        init, //Guarantees only-once execution
-        payer = authority,
-        space = 8 + GlobalState::INIT_SPACE,
+        mut,
         seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    #[account(
+        seeds = [b"wager_tier", wager_tier.tier_id.to_le_bytes().as_ref()],
+        bump = wager_tier.bump
+    )]
+    pub wager_tier: Option<Account<'info, WagerTier>>, // Required for AdminChange::WagerTier
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingAdminChange::INIT_SPACE,
+        seeds = [b"admin_change", global_state.admin_change_counter.to_le_bytes().as_ref()],
         bump
     )]
+    pub pending_change: Account<'info, PendingAdminChange>,
+    #[account(mut)]
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct ApproveAdminChange<'info> {
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    #[account(
+        mut,
+        seeds = [b"admin_change", change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingAdminChange>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCouncil<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump,
+        has_one = authority @ GameError::Unauthorized
+    )]
     pub global_state: Account<'info, GlobalState>,
-    #[account(mut)] // Must be mut because it's the payer - SOL is deducted for account creation
+    #[account(
+        init, // Only once; membership changes go through AdminChange::Council
+        payer = authority,
+        space = 8 + AdminCouncil::INIT_SPACE,
+        seeds = [b"admin_council"],
+        bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    #[account(mut)]
     pub authority: Signer<'info>,
-    // TODO play around with upgrading contracts later
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateGame<'info> {
+#[instruction(change_id: u64)]
+pub struct ExecuteAdminChange<'info> {
     #[account(
-        init,
-        payer = player,
-        space = 8 + Game::INIT_SPACE,
-        seeds = [b"game", global_state.game_counter.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
     )]
-    pub game: Account<'info, Game>,
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
     #[account(
         mut,
+        seeds = [b"wager_tier", wager_tier.tier_id.to_le_bytes().as_ref()],
+        bump = wager_tier.bump
+    )]
+    pub wager_tier: Option<Account<'info, WagerTier>>, // Required for AdminChange::WagerTier
+    #[account(
+        mut,
+        seeds = [b"admin_change", change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+        close = proposer
+    )]
+    pub pending_change: Account<'info, PendingAdminChange>,
+    #[account(mut, address = pending_change.proposed_by)]
+    pub proposer: SystemAccount<'info>, // Paid the record's rent, gets it back
+}
+
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct CancelAdminChange<'info> {
+    #[account(
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    #[account(mut)] // Must be mut because it's the payer
-    pub player: Signer<'info>,
     #[account(
-        init_if_needed,  // ← Creates profile if it doesn't exist
-        payer = player,
-        space = 8 + PlayerProfile::INIT_SPACE,
-        seeds = [b"player_profile", player.key().as_ref()],
-        bump
+        seeds = [b"admin_council"],
+        bump = council.bump
     )]
-    pub player1_profile: Account<'info, PlayerProfile>,
-    /// CHECK: Fee collector account - receives fee from games
-    #[account(mut, address = global_state.fee_collector)]
-    pub fee_collector: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    #[account(
+        mut,
+        seeds = [b"admin_change", change_id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+        close = proposer
+    )]
+    pub pending_change: Account<'info, PendingAdminChange>,
+    #[account(mut, address = pending_change.proposed_by)]
+    pub proposer: SystemAccount<'info>, // Paid the record's rent, gets it back
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
 }
 
 #[derive(Accounts)]
-#[instruction(game_id: u64)]
-pub struct JoinGame<'info> {
+pub struct AcceptAuthority<'info> {
     #[account(
         mut,
-        seeds = [b"game", game_id.to_le_bytes().as_ref()],
-        bump = game.bump
+        seeds = [b"global_state"],
+        bump = global_state.bump,
+        constraint = global_state.pending_authority.is_some() @ GameError::NoPendingAuthority,
+        constraint = global_state.pending_authority == Some(new_authority.key()) @ GameError::NotPendingAuthority
     )]
-    pub game: Account<'info, Game>,
+    pub global_state: Account<'info, GlobalState>,
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFeeShare<'info> {
     #[account(
         mut,
         seeds = [b"global_state"],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    #[account(mut)] // Must be mut because it's the payer
-    pub player: Signer<'info>,
     #[account(
-        init_if_needed,  // ← Creates profile if it doesn't exist
-        payer = player,
-        space = 8 + PlayerProfile::INIT_SPACE,
-        seeds = [b"player_profile", player.key().as_ref()],
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateReferralAccount<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralAccount::INIT_SPACE,
+        seeds = [b"referral", referrer.key().as_ref()],
         bump
     )]
-    pub player2_profile: Account<'info, PlayerProfile>,
-    /// CHECK: Fee collector account - receives fee from games
-    #[account(mut, address = global_state.fee_collector)]
-    pub fee_collector: AccountInfo<'info>,
+    pub referral_account: Account<'info, ReferralAccount>,
+    #[account(mut)]
+    pub referrer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer @ GameError::NotReferrer
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+/// Referral accounts credited when a game's escrowed SOL fees are collected at settlement
+#[derive(Accounts)]
+pub struct SettlementReferralAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"referral", player1_referral.referrer.as_ref()],
+        bump = player1_referral.bump
+    )]
+    pub player1_referral: Option<Account<'info, ReferralAccount>>, // Player1's referrer, or the referrer both players share
+    #[account(
+        mut,
+        seeds = [b"referral", player2_referral.referrer.as_ref()],
+        bump = player2_referral.bump
+    )]
+    pub player2_referral: Option<Account<'info, ReferralAccount>>, // Player2's referrer if it differs from player1's
+}
+
+impl<'info> SettlementReferralAccounts<'info> {
+    /// Accrue both players' fees, crediting their referrers
+    fn accrue_fees(
+        &mut self,
+        global_state: &mut GlobalState,
+        player1_profile: &PlayerProfile,
+        player2_profile: &PlayerProfile,
+        fee_per_player: u64,
+    ) -> Result<()> {
+        // The same account passed twice would be written back twice, dropping one of the credits
+        if let (Some(player1_referral), Some(player2_referral)) = (&self.player1_referral, &self.player2_referral) {
+            require_keys_neq!(player1_referral.key(), player2_referral.key(), GameError::DuplicateReferralAccount);
+        }
+        global_state.accrue_player_fee(player1_profile, self.player1_referral.as_deref_mut(), fee_per_player)?;
+        let player2_referral = if player2_profile.referrer.is_some() && player2_profile.referrer == player1_profile.referrer {
+            self.player1_referral.as_deref_mut()
+        } else {
+            self.player2_referral.as_deref_mut()
+        };
+        global_state.accrue_player_fee(player2_profile, player2_referral, fee_per_player)
+    }
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokenFees<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    #[account(
+        mut,
+        seeds = [b"admin_change", approved_change.change_id.to_le_bytes().as_ref()],
+        bump = approved_change.bump,
+        close = proposer
+    )]
+    pub approved_change: Option<Account<'info, PendingAdminChange>>, // Required once the council is enabled; used up
+    #[account(mut)]
+    pub proposer: Option<SystemAccount<'info>>, // Paid the approved change's rent, gets it back
+    #[account(
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program
+    )]
+    pub fee_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = global_state.fee_collector,
+        token::token_program = token_program
+    )]
+    pub fee_collector_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Option<Account<'info, AdminCouncil>>, // Required once the council is enabled
+    pub admin: Signer<'info>, // Authority, or a council member once the council is enabled
+}
+
 #[derive(Accounts)]
 #[instruction(game_id: u64)]
-pub struct RevealMove<'info> {
+pub struct ClaimTimeoutVictory<'info> {
     #[account(
         mut,
         seeds = [b"game", game_id.to_le_bytes().as_ref()],
@@ -630,34 +3403,75 @@ pub struct RevealMove<'info> {
     /// CHECK: Player2 account for payout  
     #[account(mut, address = game.player2)]
     pub player2: AccountInfo<'info>,
-}
-
-#[derive(Accounts)]
-pub struct UpdatePlayerName<'info> {
     #[account(
         mut,
-        seeds = [b"player_profile", player.key().as_ref()],
-        bump = player_profile.bump
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
     )]
-    pub player_profile: Account<'info, PlayerProfile>,
-    pub player: Signer<'info>, 
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if either player staked from available_funds
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    pub referrals: SettlementReferralAccounts<'info>, // Referrers credited with escrowed SOL fees
 }
 
 #[derive(Accounts)]
-pub struct UpdateFeeCollector<'info> {
+#[instruction(game_id: u64)]
+pub struct SettleExpiredReveal<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game_id.to_le_bytes().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
     #[account(
         mut,
         seeds = [b"global_state"],
-        bump = global_state.bump,
-        has_one = authority @ GameError::Unauthorized
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
-    pub authority: Signer<'info>,
+    pub caller: Signer<'info>, // Anyone can settle once the deadline passes
+    #[account(
+        mut,
+        seeds = [b"player_profile", game.player1.as_ref()],
+        bump = player1_profile.bump
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        mut,
+        seeds = [b"player_profile", game.player2.as_ref()],
+        bump = player2_profile.bump
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// CHECK: Player1 account for refund
+    #[account(mut, address = game.player1)]
+    pub player1: AccountInfo<'info>,
+    /// CHECK: Player2 account for refund
+    #[account(mut, address = game.player2)]
+    pub player2: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if either player staked from available_funds
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    pub referrals: SettlementReferralAccounts<'info>, // Referrers credited with escrowed SOL fees
 }
 
 #[derive(Accounts)]
 #[instruction(game_id: u64)]
-pub struct ClaimTimeoutVictory<'info> {
+pub struct ClaimCommitTimeout<'info> {
     #[account(
         mut,
         seeds = [b"game", game_id.to_le_bytes().as_ref()],
@@ -689,6 +3503,20 @@ pub struct ClaimTimeoutVictory<'info> {
     /// CHECK: Player2 account for payout  
     #[account(mut, address = game.player2)]
     pub player2: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(
+        mut,
+        seeds = [b"funds_vault"],
+        bump = funds_vault.bump
+    )]
+    pub funds_vault: Option<Account<'info, FundsVault>>, // Required if either player staked from available_funds
+    pub token: SettlementTokenAccounts<'info>, // SPL escrow accounts (None = SOL game)
+    pub referrals: SettlementReferralAccounts<'info>, // Referrers credited with escrowed SOL fees
 }
 
 #[derive(Accounts)]
@@ -1,14 +1,108 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::*;
+
+pub const MAX_SERIES_LENGTH: u8 = 7; // Longest best-of-N series
+pub const BPS_DENOMINATOR: u64 = 10_000; // 100% in basis points
+pub const MAX_FEE_SHARES: usize = 5; // Fee split recipients besides the fee collector
+pub const MAX_COUNCIL_MEMBERS: usize = 10; // Admin council size limit
+pub const GLOBAL_STATE_VERSION: u8 = 1; // Current GlobalState layout (0 = deployed before the appended fields)
+pub const PLAYER_PROFILE_VERSION: u8 = 1; // Current PlayerProfile layout (0 = deployed before the appended fields)
+pub const GAME_VERSION: u8 = 1; // Current Game layout (games of the first deployment are migrated by migrate_game)
+
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalState {
+    // Deployed layout - existing accounts are migrated by appending, so these must never move
     pub game_counter: u64,          // Next game ID to use
     pub total_games_completed: u64, // Total completed 
     pub authority: Pubkey,          // For application admin functions
-    pub fee_collector: Pubkey,      // Address that receives 1% fee from all games
-    pub fee_percentage: u64,        // Fee percentage (e.g., 1 for 1%)
+    pub fee_collector: Pubkey,      // Address withdraw_fees pays accrued fees out to
+    pub legacy_fee_percentage: u64, // Whole-percent fee before fee_bps; only read by migrate_global_state
     pub bump: u8,                   // PDA bump for global state
+    // Appended fields - new fields go at the end, never in between
+    pub version: u8,                // Layout version, see GLOBAL_STATE_VERSION
+    pub pending_authority: Option<Pubkey>, // Proposed new authority until it accepts (two-step transfer)
+    pub new_games_paused: bool,     // Blocks creating/joining games and deposits; games in play still settle
+    pub paused: bool,               // Blocks every player instruction except commits and reveals
+    pub fee_bps: u16,               // Fee in basis points (e.g., 100 for 1%)
+    pub max_fee_bps: u16,           // Authority-set cap on fee_bps and tier fees
+    pub min_fee: u64,               // Smallest fee per player, in lamports (0 = no minimum)
+    pub max_fee: u64,               // Largest fee per player, in lamports (0 = no maximum)
+    pub min_wager: u64,             // Smallest wager accepted by create_game, in lamports
+    pub max_wager: u64,             // Largest wager accepted by create_game, in lamports
+    pub wager_tier_counter: u32,    // Next wager tier ID to use
+    pub no_reveal_penalty_percentage: u64, // Share of each net wager kept when neither player reveals
+    pub tie_policy: TiePolicy,      // How ties are resolved for new games
+    pub total_fees_accrued: u64,    // Lifetime SOL fees moved into the fee vault, in lamports
+    pub total_fees_withdrawn: u64,  // Lifetime SOL fees withdrawn from the fee vault, in lamports
+    #[max_len(MAX_FEE_SHARES)]
+    pub fee_shares: Vec<FeeShare>,  // Revenue split of all fees; the remainder goes to the fee collector
+    pub referral_bps: u16,          // Share of a referred player's SOL fees owed to their referrer (SPL fees aren't shared)
+    pub unclaimed_referral_fees: u64, // Referral fees held in the fee vault until referrers claim them
+    pub admin_change_delay: i64,    // Seconds a queued admin change waits before it can be executed
+    pub admin_change_counter: u64,  // Next admin change ID to use
+    pub council_enabled: bool,      // Admin changes need admin council approvals instead of the authority
+}
+
+impl GlobalState {
+    /// Fail if the authority has paused the whole program
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.paused, GameError::ProgramPaused);
+        Ok(())
+    }
+
+    /// Fail if new money can't come in: new games are paused, or everything is
+    pub fn require_new_games_allowed(&self) -> Result<()> {
+        self.require_not_paused()?;
+        require!(!self.new_games_paused, GameError::NewGamesPaused);
+        Ok(())
+    }
+
+    /// Add SOL fees moved into the fee vault to the running total and credit each fee share.
+    /// Shares round down, so rounding dust stays with the fee collector.
+    pub fn accrue_fees(&mut self, amount: u64) -> Result<()> {
+        self.total_fees_accrued = self.total_fees_accrued.checked_add(amount).ok_or(GameError::MathOverflow)?;
+        for share in self.fee_shares.iter_mut() {
+            let cut = (amount as u128 * share.bps as u128 / BPS_DENOMINATOR as u128) as u64;
+            share.claimable = share.claimable.checked_add(cut).ok_or(GameError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Accrue a SOL fee paid by `payer`. Their referrer's share is carved out first, credited to
+    /// the referrer's referral account, and the fee split only applies to the rest. SPL fees
+    /// never reach this (they are split on withdrawal), so referrers only earn from SOL games.
+    pub fn accrue_player_fee(
+        &mut self,
+        payer: &PlayerProfile,
+        referral: Option<&mut ReferralAccount>,
+        fee: u64,
+    ) -> Result<()> {
+        let mut referral_fee = 0;
+        if payer.referrer.is_some() {
+            referral_fee = (fee as u128 * self.referral_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        }
+        if referral_fee > 0 {
+            let referral = referral.ok_or(GameError::ReferralAccountRequired)?;
+            require!(Some(referral.referrer) == payer.referrer, GameError::NotReferrer);
+            referral.claimable = referral.claimable.checked_add(referral_fee).ok_or(GameError::MathOverflow)?;
+            self.unclaimed_referral_fees = self.unclaimed_referral_fees.checked_add(referral_fee).ok_or(GameError::MathOverflow)?;
+            self.total_fees_accrued = self.total_fees_accrued.checked_add(referral_fee).ok_or(GameError::MathOverflow)?;
+        }
+        self.accrue_fees(fee - referral_fee)
+    }
+
+    /// Add SOL fees paid out of the fee vault to the running total
+    pub fn record_fees_withdrawn(&mut self, amount: u64) -> Result<()> {
+        self.total_fees_withdrawn = self.total_fees_withdrawn.checked_add(amount).ok_or(GameError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Lamports in the fee vault owed to fee split recipients and referrers
+    pub fn unclaimed_fees(&self) -> u64 {
+        self.fee_shares.iter().map(|share| share.claimable).sum::<u64>() + self.unclaimed_referral_fees
+    }
 }
 
 #[account]
@@ -17,7 +111,7 @@ pub struct PlayerProfile {
     pub player: Pubkey,             // Owner of this profile
     #[max_len(32)]
     pub name: String,
-    pub available_funds: u64,       // TODO implement funds
+    pub available_funds: u64,       // Internal balance held in the funds vault, in lamports
     pub total_games_played: u64,    // Games played
     pub total_games_completed: u64, 
     pub total_games_forfeited: u64,
@@ -31,29 +125,103 @@ pub struct PlayerProfile {
     pub best_streak: u32,           //TODO // Best win streak ever
     pub created_at: i64,            // Account creation timestamp
     pub bump: u8,
+    // Appended after the first deployment; migrate_player_profile grows older profiles
+    pub version: u8,                // Layout version, see PLAYER_PROFILE_VERSION
+    pub total_games_cancelled: u64, // Games withdrawn before anyone joined
+    pub active_games: u32,          // Games created/joined that are not finished or cancelled
+    pub referrer: Option<Pubkey>,   // Copied from the player's referral link when the profile is created
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
-pub enum WagerAmount {
-    Sol1,    // 1.0 SOL
-    Sol01,   // 0.1 SOL  
-    Sol001,  // 0.01 SOL
-}
+impl PlayerProfile {
+    /// Add to lifetime betting volume: lamports on the profile for SOL games, raw token units
+    /// on the player's stats for the game's mint otherwise
+    pub fn record_wagered(&mut self, mint_stats: Option<&mut PlayerMintStats>, game: &Game, amount: u64) -> Result<()> {
+        let total = match game.mint {
+            Some(_) => &mut self.mint_stats(mint_stats, game)?.total_wagered,
+            None => &mut self.total_wagered,
+        };
+        *total = total.checked_add(amount).ok_or(GameError::MathOverflow)?;
+        Ok(())
+    }
 
-impl WagerAmount {
-    /// Convert enum to lamports (1 SOL = 1_000_000_000 lamports)
-    pub fn to_lamports(&self) -> u64 {
-        match self {
-            WagerAmount::Sol1 => 1_000_000_000,    // 1 SOL
-            WagerAmount::Sol01 => 100_000_000,     // 0.1 SOL
-            WagerAmount::Sol001 => 10_000_000,     // 0.01 SOL
-        }
+    /// Add to lifetime winnings in the game's currency
+    pub fn record_won(&mut self, mint_stats: Option<&mut PlayerMintStats>, game: &Game, amount: u64) -> Result<()> {
+        let total = match game.mint {
+            Some(_) => &mut self.mint_stats(mint_stats, game)?.total_won,
+            None => &mut self.total_won,
+        };
+        *total = total.checked_add(amount).ok_or(GameError::MathOverflow)?;
+        Ok(())
     }
 
-    /// Calculate fee per player based on percentage (used only at game creation)
-    pub fn fee_per_player(&self, fee_percentage: u64) -> u64 {
-        (self.to_lamports() * fee_percentage) / 100
+    /// Add to lifetime losses in the game's currency
+    pub fn record_lost(&mut self, mint_stats: Option<&mut PlayerMintStats>, game: &Game, amount: u64) -> Result<()> {
+        let total = match game.mint {
+            Some(_) => &mut self.mint_stats(mint_stats, game)?.total_lost,
+            None => &mut self.total_lost,
+        };
+        *total = total.checked_add(amount).ok_or(GameError::MathOverflow)?;
+        Ok(())
     }
+
+    /// This player's stats for an SPL game's mint
+    fn mint_stats<'a>(&self, mint_stats: Option<&'a mut PlayerMintStats>, game: &Game) -> Result<&'a mut PlayerMintStats> {
+        let mint_stats = mint_stats.ok_or(GameError::MintStatsRequired)?;
+        require!(
+            mint_stats.player == self.player && Some(mint_stats.mint) == game.mint,
+            GameError::MintStatsRequired
+        );
+        Ok(mint_stats)
+    }
+}
+
+/// A referrer's referral stats and the SOL fees their referred players have accrued to them.
+/// Never closed, so unclaimed fees survive the referred players closing their profiles.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralAccount {
+    pub referrer: Pubkey,
+    pub referral_count: u32,        // Players who signed up with this referrer
+    pub claimable: u64,             // Referral fees accrued but not yet claimed, in lamports
+    pub total_claimed: u64,         // Lifetime referral fees claimed, in lamports
+    pub bump: u8,
+}
+
+/// Who referred a player. Never closed, so a closed and re-created profile keeps its referrer.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralLink {
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub bump: u8,
+}
+
+/// A player's lifetime stats in one SPL mint, in that mint's raw token units
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerMintStats {
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub total_wagered: u64,
+    pub total_won: u64,
+    pub total_lost: u64,
+    pub bump: u8,
+}
+
+/// Calculate fee per player from a basis-point rate, clamped to [min_fee, max_fee] (0 = unbounded).
+/// Used only at game creation. Rounds up so no wager amount can round its fee down to zero.
+pub fn fee_per_player(wager: u64, fee_bps: u16, min_fee: u64, max_fee: u64) -> Result<u64> {
+    let fee = (wager as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(GameError::MathOverflow)?
+        .div_ceil(BPS_DENOMINATOR as u128);
+    let mut fee = u64::try_from(fee).map_err(|_| GameError::MathOverflow)?.max(min_fee);
+    if max_fee > 0 {
+        fee = fee.min(max_fee);
+    }
+    // The fee must leave a net wager to play for
+    require!(fee < wager, GameError::FeeExceedsWager);
+    Ok(fee)
 }
 
 #[account]
@@ -62,8 +230,18 @@ pub struct Game {
     pub game_id: u64,               // Unique game identifier
     pub player1: Pubkey,
     pub player2: Pubkey,
-    pub wager: WagerAmount,
+    pub wager: u64,                 // Wager per player in lamports, or raw token units for SPL games
+    pub mint: Option<Pubkey>,       // SPL mint the wager is paid in (None = SOL)
+    pub player1_uses_funds: bool,   // Player1 staked from available_funds; payouts are credited back
+    pub player2_uses_funds: bool,   // Player2 staked from available_funds; payouts are credited back
+    pub wager_tier: Option<u32>,    // Tier the wager terms were taken from (None = free wager)
     pub fee_per_player: u64,        // Fee amount locked at game creation
+    pub series_length: u8,          // Best-of-N rounds (1 = single round)
+    #[max_len(MAX_SERIES_LENGTH)]
+    pub round_results: Vec<Winner>, // Decisive round results (Player1/Player2) so far
+    pub round_ties: u8,             // Tied rounds, replayed without counting toward N
+    pub no_reveal_penalty: u64,     // Per-player penalty locked at game creation
+    pub tie_policy: TiePolicy,      // Tie policy locked at game creation
     pub state: GameState,
     pub player1_move_hash: Option<[u8; 32]>,
     pub player2_move_hash: Option<[u8; 32]>,
@@ -71,19 +249,248 @@ pub struct Game {
     pub player2_move: Option<Move>,
     pub winner_type: Option<Winner>,    // WHO won (Player1/Player2/Tie)
     pub winner_address: Option<Pubkey>, // WHICH address won
+    pub commit_deadline: Option<i64>,   // Timestamp when non-committer forfeits
     pub reveal_deadline: Option<i64>,   // Timestamp when non-revealer forfeits
+    pub invited_player: Option<Pubkey>, // Only this wallet may join (private challenge)
+    pub invite_expiry: Option<i64>,     // Timestamp after which the invite can no longer be accepted
+    pub join_secret_hash: Option<[u8; 32]>, // hash(secret) required to join (password game)
+    pub previous_game_id: Option<u64>,  // Game this one is a rematch of
+    pub rematch_game_id: Option<u64>,   // Rematch game created from this one
+    pub player1_token_surplus: u64,     // Transfer fee rounding player1's deposits left in the escrow
+    pub player2_token_surplus: u64,     // Transfer fee rounding player2's deposits left in the escrow
+    pub bump: u8,
+    pub version: u8,                    // Layout version, see GAME_VERSION
+}
+
+/// Fixed wagers of the first deployment, only read when migrating its games
+#[derive(AnchorDeserialize)]
+pub enum LegacyWagerAmount {
+    Sol1,    // 1.0 SOL
+    Sol01,   // 0.1 SOL
+    Sol001,  // 0.01 SOL
+}
+
+impl LegacyWagerAmount {
+    pub fn to_lamports(&self) -> u64 {
+        match self {
+            LegacyWagerAmount::Sol1 => 1_000_000_000,
+            LegacyWagerAmount::Sol01 => 100_000_000,
+            LegacyWagerAmount::Sol001 => 10_000_000,
+        }
+    }
+}
+
+/// Game layout of the first deployment, only read when migrating its games.
+/// Its states, moves and results are the first variants of today's enums.
+#[derive(AnchorDeserialize)]
+pub struct LegacyGame {
+    pub game_id: u64,
+    pub player1: Pubkey,
+    pub player2: Pubkey,
+    pub wager: LegacyWagerAmount,
+    pub fee_per_player: u64,
+    pub state: GameState,
+    pub player1_move_hash: Option<[u8; 32]>,
+    pub player2_move_hash: Option<[u8; 32]>,
+    pub player1_move: Option<Move>,
+    pub player2_move: Option<Move>,
+    pub winner_type: Option<Winner>,
+    pub winner_address: Option<Pubkey>,
+    pub reveal_deadline: Option<i64>,
     pub bump: u8,
 }
 
 impl Game {
     /// Calculate net wager per player (after locked fee)
-    pub fn net_per_player(&self) -> u64 {
-        self.wager.to_lamports() - self.fee_per_player
+    pub fn net_per_player(&self) -> Result<u64> {
+        Ok(self.wager.checked_sub(self.fee_per_player).ok_or(GameError::MathOverflow)?)
     }
 
     /// Calculate total pot for winner (both players' net contributions)
-    pub fn total_pot(&self) -> u64 {
-        self.net_per_player() * 2
+    pub fn total_pot(&self) -> Result<u64> {
+        Ok(self.net_per_player()?.checked_mul(2).ok_or(GameError::MathOverflow)?)
+    }
+
+    /// What each player's stake left in the game: the net wager, plus the fee under RefundWithFees
+    pub fn escrowed_per_player(&self) -> Result<u64> {
+        match self.tie_policy {
+            TiePolicy::RefundWithFees => Ok(self.wager),
+            _ => self.net_per_player(),
+        }
+    }
+
+    /// Both players' no-reveal penalties together
+    pub fn total_no_reveal_penalty(&self) -> Result<u64> {
+        Ok(self.no_reveal_penalty.checked_mul(2).ok_or(GameError::MathOverflow)?)
+    }
+
+    /// Rounds won by (player1, player2) so far in a series
+    pub fn round_wins(&self) -> (u8, u8) {
+        let player1_wins = self.round_results.iter().filter(|r| **r == Winner::Player1).count() as u8;
+        let player2_wins = self.round_results.len() as u8 - player1_wins;
+        (player1_wins, player2_wins)
+    }
+
+    /// 1-based number of the round being played, tied rounds included
+    pub fn current_round(&self) -> u8 {
+        self.round_results.len() as u8 + self.round_ties.saturating_add(1)
+    }
+
+    pub fn is_first_round(&self) -> bool {
+        self.round_results.is_empty() && self.round_ties == 0
+    }
+
+    /// Record a round result; returns the series winner once a player holds the majority
+    pub fn record_round(&mut self, round_winner: Winner) -> Option<Winner> {
+        match round_winner {
+            Winner::Player1 | Winner::Player2 => self.round_results.push(round_winner),
+            _ => {
+                self.round_ties = self.round_ties.saturating_add(1);
+                return None;
+            }
+        }
+
+        let majority = self.series_length / 2 + 1;
+        let (player1_wins, player2_wins) = self.round_wins();
+        if player1_wins >= majority {
+            Some(Winner::Player1)
+        } else if player2_wins >= majority {
+            Some(Winner::Player2)
+        } else {
+            None
+        }
+    }
+
+    /// Credit the rounding surplus of a deposit into the escrow to the player who made it
+    pub fn record_token_surplus(&mut self, player: &Pubkey, surplus: u64) -> Result<()> {
+        let recorded = if *player == self.player1 {
+            &mut self.player1_token_surplus
+        } else {
+            &mut self.player2_token_surplus
+        };
+        *recorded = recorded.checked_add(surplus).ok_or(GameError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Address that receives the pot for a given outcome (None when both are refunded)
+    pub fn winner_address(&self, winner_type: Winner) -> Option<Pubkey> {
+        match winner_type {
+            Winner::Player1 | Winner::Player1OpponentForfeit | Winner::Player1OpponentNoCommit => Some(self.player1),
+            Winner::Player2 | Winner::Player2OpponentForfeit | Winner::Player2OpponentNoCommit => Some(self.player2),
+            Winner::Tie | Winner::NoCommitRefund | Winner::NoRevealRefund => None,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct WagerTier {
+    pub tier_id: u32,
+    pub wager: u64,                 // Wager per player in lamports
+    pub fee_bps: u16,               // Fee in basis points for games at this tier
+    pub enabled: bool,              // Disabled tiers can't be used for new games
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeVault {
+    pub bump: u8,                   // Holds accrued SOL fees; SPL fees sit in its associated token accounts
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FundsVault {
+    pub bump: u8,                   // Holds every player's available_funds lamports
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct JoinTicket {
+    pub game_id: u64,
+    pub player: Pubkey,             // Player allowed to use this ticket
+    pub commitment: [u8; 32],       // hash(secret || player)
+    pub slot: u64,                  // Slot the ticket was requested in
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct FeeShare {
+    pub recipient: Pubkey,
+    pub bps: u16,                   // Share of each fee in basis points (SPL shares are paid on withdrawal)
+    pub claimable: u64,             // Accrued lamports not yet claimed
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct FeeSplitEntry {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+/// Parameter changes and admin call approvals that only go through the admin change timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AdminChange {
+    FeeBps { fee_bps: u16 },
+    FeeLimits { max_fee_bps: u16, min_fee: u64, max_fee: u64 }, // 0 = no lamport bound
+    FeeCollector { fee_collector: Pubkey },
+    FeeSplit {
+        #[max_len(MAX_FEE_SHARES)]
+        entries: Vec<FeeSplitEntry>,
+    },
+    ReferralBps { referral_bps: u16 },
+    ChangeDelay { delay_seconds: i64 },
+    WagerTier { tier_id: u32, wager: u64, fee_bps: u16, enabled: bool }, // Reprice, enable or disable a tier
+    NoRevealPenalty { percentage: u64 }, // Share of each net wager kept when neither player reveals
+    Council {
+        #[max_len(MAX_COUNCIL_MEMBERS)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+    Authority { new_authority: Option<Pubkey> }, // Propose an authority transfer, or cancel it with None
+    WagerLimits { min_wager: u64, max_wager: u64 }, // Lamport range accepted by create_game
+    TiePolicy { tie_policy: TiePolicy }, // How ties are resolved in new games
+    Pause { new_games_paused: bool, paused: bool }, // Lifting a pause under the council
+    WithdrawFees { amount: u64 }, // Approves one withdraw_fees call
+    WithdrawTokenFees { mint: Pubkey, amount: u64 }, // Approves one withdraw_token_fees call
+    AddWagerTier { wager: u64, fee_bps: u16 }, // Approves one add_wager_tier call
+}
+
+impl AdminChange {
+    /// Changes that approve a single admin call, which checks and uses them up, rather than
+    /// being applied by execute_admin_change
+    pub fn approves_call(&self) -> bool {
+        matches!(
+            self,
+            AdminChange::WithdrawFees { .. } | AdminChange::WithdrawTokenFees { .. } | AdminChange::AddWagerTier { .. }
+        )
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAdminChange {
+    pub change_id: u64,
+    pub change: AdminChange,
+    pub proposed_by: Pubkey,        // Authority or member that queued the change and paid the rent
+    pub effective_at: i64,          // Earliest time the change can be executed
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub approvals: Vec<Pubkey>,     // Council members who approved (unused without a council)
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminCouncil {
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,              // Approvals an admin change needs (M of N)
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    /// Approvals on a pending change that come from current members
+    pub fn approvals(&self, pending_change: &PendingAdminChange) -> u8 {
+        pending_change.approvals.iter().filter(|member| self.members.contains(member)).count() as u8
     }
 }
 
@@ -95,6 +502,13 @@ pub enum GameState {
     Finished,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum TiePolicy {
+    Refund,          // Refund net wagers, fees are kept
+    Replay,          // Replay with new commits in the same game
+    RefundWithFees,  // Refund full wagers; fees stay in the game until settlement
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum Move {
     Rock,
@@ -109,4 +523,8 @@ pub enum Winner {
     Tie,
     Player1OpponentForfeit,  // Player1 wins because Player2 forfeited; didn't reveal in time
     Player2OpponentForfeit,  // Player2 wins because Player1 forfeited; didn't reveal in time
+    Player1OpponentNoCommit, // Player1 wins because Player2 didn't commit in time
+    Player2OpponentNoCommit, // Player2 wins because Player1 didn't commit in time
+    NoCommitRefund,          // Neither player committed in time; both are refunded
+    NoRevealRefund,          // Neither player revealed in time; both are refunded minus penalty
 }
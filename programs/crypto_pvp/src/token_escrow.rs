@@ -0,0 +1,444 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    create_idempotent, get_associated_token_address_with_program_id, AssociatedToken, Create,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022_extensions::transfer_fee::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint};
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+
+// Token-2022 mint extensions that can't freeze, claw back or reroute escrowed tokens
+const SUPPORTED_MINT_EXTENSIONS: &[ExtensionType] = &[
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+/// Token accounts used to deposit an SPL wager (all None for SOL games)
+#[derive(Accounts)]
+pub struct WagerTokenAccounts<'info> {
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// CHECK: Game's escrow ATA - created if needed, address verified against the game PDA
+    #[account(mut)]
+    pub escrow: Option<UncheckedAccount<'info>>,
+    /// CHECK: Player's source token account - the token program checks the player signed
+    #[account(mut)]
+    pub player_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Fee vault's ATA - created if needed, address verified against the fee vault PDA
+    #[account(mut)]
+    pub fee_vault_token_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+/// Token accounts used to pay out of an SPL game's escrow (all None for SOL games)
+#[derive(Accounts)]
+pub struct SettlementTokenAccounts<'info> {
+    #[account(mut)] // Withheld Token-2022 transfer fees are harvested to the mint before the escrow closes
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// CHECK: Game's escrow ATA - address verified against the game PDA
+    #[account(mut)]
+    pub escrow: Option<UncheckedAccount<'info>>,
+    /// CHECK: Player1's ATA - address verified against game.player1
+    #[account(mut)]
+    pub player1_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Player2's ATA - address verified against game.player2
+    #[account(mut)]
+    pub player2_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Fee vault's ATA - address verified against the fee vault PDA
+    #[account(mut)]
+    pub fee_vault_token_account: Option<UncheckedAccount<'info>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    #[account(
+        mut,
+        seeds = [b"player_mint_stats", player1_mint_stats.player.as_ref(), player1_mint_stats.mint.as_ref()],
+        bump = player1_mint_stats.bump
+    )]
+    pub player1_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required to settle SPL games
+    #[account(
+        mut,
+        seeds = [b"player_mint_stats", player2_mint_stats.player.as_ref(), player2_mint_stats.mint.as_ref()],
+        bump = player2_mint_stats.bump
+    )]
+    pub player2_mint_stats: Option<Account<'info, PlayerMintStats>>, // Required to settle SPL games
+}
+
+/// Mint, escrow and token program of an SPL game
+pub struct TokenEscrow<'info> {
+    pub mint: AccountInfo<'info>,
+    pub decimals: u8,
+    pub escrow: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Token accounts a player's SPL wager moves through
+pub struct TokenDeposit<'info> {
+    pub escrow: TokenEscrow<'info>,
+    pub source: AccountInfo<'info>,
+    pub fee_destination: AccountInfo<'info>,
+}
+
+/// Where settlement funds go: wallets for SOL games, associated token accounts for SPL games
+pub struct Payees<'info> {
+    pub player1: AccountInfo<'info>,
+    pub player2: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    pub token: Option<TokenEscrow<'info>>,
+}
+
+impl<'info> WagerTokenAccounts<'info> {
+    /// Mint of the wager, default pubkey for SOL wagers (seeds the player's per-mint stats)
+    pub fn mint_key(&self) -> Pubkey {
+        self.mint.as_ref().map_or(Pubkey::default(), |mint| mint.key())
+    }
+
+    /// Resolve the accounts an SPL wager moves through, creating the escrow and fee ATAs if needed
+    pub fn deposit(
+        &self,
+        game: &Account<'info, Game>,
+        fee_vault: &AccountInfo<'info>,
+        player: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<Option<TokenDeposit<'info>>> {
+        let Some(escrow) = resolve_escrow(game, &self.mint, &self.escrow, &self.token_program)? else {
+            return Ok(None);
+        };
+        // Only checked on the way in, so an escrow that already holds tokens can always pay out
+        check_mint_extensions(&escrow.mint)?;
+        let fee_destination = associated_account(fee_vault.key, &escrow, &self.fee_vault_token_account)?;
+        let source = self
+            .player_token_account
+            .as_ref()
+            .ok_or(GameError::TokenAccountsRequired)?
+            .to_account_info();
+        let associated_token_program = self
+            .associated_token_program
+            .as_ref()
+            .ok_or(GameError::TokenAccountsRequired)?
+            .to_account_info();
+
+        // Player pays for the escrow and fee vault ATAs the first time they are needed
+        for (account, authority) in [
+            (&escrow.escrow, game.to_account_info()),
+            (&fee_destination, fee_vault.clone()),
+        ] {
+            create_idempotent(CpiContext::new(
+                associated_token_program.clone(),
+                Create {
+                    payer: player.clone(),
+                    associated_token: account.clone(),
+                    authority,
+                    mint: escrow.mint.clone(),
+                    system_program: system_program.clone(),
+                    token_program: escrow.token_program.clone(),
+                },
+            ))?;
+        }
+
+        Ok(Some(TokenDeposit { escrow, source, fee_destination }))
+    }
+}
+
+impl<'info> SettlementTokenAccounts<'info> {
+    /// Mint, escrow and token program of the game; None for SOL games
+    pub fn escrow(&self, game: &Account<'info, Game>) -> Result<Option<TokenEscrow<'info>>> {
+        resolve_escrow(game, &self.mint, &self.escrow, &self.token_program)
+    }
+
+    /// Resolve payout destinations for the game's currency
+    pub fn payees(
+        &self,
+        game: &Account<'info, Game>,
+        player1: &AccountInfo<'info>,
+        player2: &AccountInfo<'info>,
+        fee_vault: &AccountInfo<'info>,
+    ) -> Result<Payees<'info>> {
+        let Some(token) = self.escrow(game)? else {
+            return Ok(Payees {
+                player1: player1.clone(),
+                player2: player2.clone(),
+                fee_vault: fee_vault.clone(),
+                token: None,
+            });
+        };
+
+        Ok(Payees {
+            player1: associated_account(&game.player1, &token, &self.player1_token_account)?,
+            player2: associated_account(&game.player2, &token, &self.player2_token_account)?,
+            fee_vault: associated_account(fee_vault.key, &token, &self.fee_vault_token_account)?,
+            token: Some(token),
+        })
+    }
+
+    /// Close the game's escrow: each player gets back the deposit rounding surplus they left in it,
+    /// anything else still held goes to player1 and the rent to `rent_receiver`
+    pub fn close_escrow(
+        &self,
+        game: &Account<'info, Game>,
+        token: &TokenEscrow<'info>,
+        rent_receiver: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let player1_destination = associated_account(&game.player1, token, &self.player1_token_account)?;
+        let mut leftover = escrow_balance(game, Some(token))?;
+
+        // player2's ATA is only needed when their deposit left a surplus behind
+        let player2_refund = game.player2_token_surplus.min(leftover);
+        if player2_refund > 0 {
+            let player2_destination = associated_account(&game.player2, token, &self.player2_token_account)?;
+            pay_from_escrow(game, Some(token), &player2_destination, player2_refund)?;
+            leftover -= player2_refund;
+        }
+        pay_from_escrow(game, Some(token), &player1_destination, leftover)?;
+
+        close_empty_escrow(game, token, rent_receiver)
+    }
+}
+
+/// Helper function to check the token accounts passed for an SPL game against the game's mint
+fn resolve_escrow<'info>(
+    game: &Account<'info, Game>,
+    mint: &Option<Box<InterfaceAccount<'info, Mint>>>,
+    escrow: &Option<UncheckedAccount<'info>>,
+    token_program: &Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenEscrow<'info>>> {
+    let Some(game_mint) = game.mint else {
+        return Ok(None);
+    };
+    let mint = mint.as_ref().ok_or(GameError::TokenAccountsRequired)?;
+    let token_program = token_program.as_ref().ok_or(GameError::TokenAccountsRequired)?;
+    let escrow = escrow.as_ref().ok_or(GameError::TokenAccountsRequired)?;
+
+    require_keys_eq!(mint.key(), game_mint, GameError::InvalidTokenAccount);
+    require!(
+        token_program.key() == anchor_spl::token::ID || token_program.key() == anchor_spl::token_2022::ID,
+        GameError::UnsupportedTokenProgram
+    );
+    require_keys_eq!(*mint.to_account_info().owner, token_program.key(), GameError::UnsupportedTokenProgram);
+    require_keys_eq!(
+        escrow.key(),
+        get_associated_token_address_with_program_id(&game.key(), &game_mint, &token_program.key()),
+        GameError::InvalidTokenAccount
+    );
+
+    Ok(Some(TokenEscrow {
+        mint: mint.to_account_info(),
+        decimals: mint.decimals,
+        escrow: escrow.to_account_info(),
+        token_program: token_program.to_account_info(),
+    }))
+}
+
+/// Helper function to check a passed token account is the owner's ATA for the game's mint
+fn associated_account<'info>(
+    owner: &Pubkey,
+    token: &TokenEscrow<'info>,
+    account: &Option<UncheckedAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    let account = account.as_ref().ok_or(GameError::TokenAccountsRequired)?;
+    require_keys_eq!(
+        account.key(),
+        get_associated_token_address_with_program_id(owner, token.mint.key, token.token_program.key),
+        GameError::InvalidTokenAccount
+    );
+    Ok(account.to_account_info())
+}
+
+/// Helper function to reject Token-2022 mints with extensions outside SUPPORTED_MINT_EXTENSIONS
+/// (e.g. non-transferable, permanent delegate, transfer hooks, default-frozen accounts)
+fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    for extension in mint_state.get_extension_types()? {
+        require!(SUPPORTED_MINT_EXTENSIONS.contains(&extension), GameError::UnsupportedMintExtension);
+    }
+    Ok(())
+}
+
+/// Helper function to read a Token-2022 mint's transfer fee config (None = no transfer fee)
+fn transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(None);
+    }
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(mint_state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Helper function to read a token account's balance
+fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+/// Helper function to move part of a player's SPL wager from their token account
+/// (into the game's escrow, or the fee vault's ATA). Transfer fees are added on top so
+/// `to` receives at least `amount`, keeping pot math exact; returns the rounding surplus
+/// left in `to` on top of `amount`.
+pub fn transfer_from_player<'info>(
+    player: &AccountInfo<'info>,
+    deposit: &TokenDeposit<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let transfer_fee = match transfer_fee_config(&deposit.escrow.mint)? {
+        Some(config) => config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(GameError::InvalidTransferFee)?,
+        None => 0,
+    };
+    let gross_amount = amount.checked_add(transfer_fee).ok_or(GameError::InvalidTransferFee)?;
+
+    let balance_before = token_balance(to)?;
+    transfer_checked(
+        CpiContext::new(
+            deposit.escrow.token_program.clone(),
+            TransferChecked {
+                from: deposit.source.clone(),
+                mint: deposit.escrow.mint.clone(),
+                to: to.clone(),
+                authority: player.clone(),
+            },
+        ),
+        gross_amount,
+        deposit.escrow.decimals,
+    )?;
+
+    // Escrow accounting relies on what actually arrived, not what was sent
+    let received = token_balance(to)? - balance_before;
+    require!(received >= amount, GameError::InvalidTransferFee);
+    if transfer_fee > 0 {
+        msg!("Token transfer fee: {} units paid by {}", transfer_fee, player.key());
+    }
+    Ok(received - amount)
+}
+
+/// Helper function to pay out of the game: lamports from the game account for SOL games,
+/// tokens from the escrow (signed by the game PDA) for SPL games. Payouts are not grossed up:
+/// Token-2022 transfer fees are withheld from what the recipient receives, and the net amount
+/// is emitted as a TokenPayout event.
+pub fn pay_from_escrow<'info>(
+    game: &Account<'info, Game>,
+    token: Option<&TokenEscrow<'info>>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let Some(token) = token else {
+        **game.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        return Ok(());
+    };
+
+    let transfer_fee = match transfer_fee_config(&token.mint)? {
+        Some(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(GameError::InvalidTransferFee)?,
+        None => 0,
+    };
+
+    let game_id = game.game_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"game", game_id.as_ref(), &[game.bump]]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token.token_program.clone(),
+            TransferChecked {
+                from: token.escrow.clone(),
+                mint: token.mint.clone(),
+                to: to.clone(),
+                authority: game.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        token.decimals,
+    )?;
+
+    emit!(TokenPayout {
+        game_id: game.game_id,
+        mint: token.mint.key(),
+        recipient: to.key(),
+        amount,
+        transfer_fee,
+        net_amount: amount - transfer_fee,
+    });
+    Ok(())
+}
+
+/// Helper function to pay SPL fees out of the fee vault's token account, signed by the fee vault PDA
+pub fn pay_from_fee_vault<'info>(
+    fee_vault: &Account<'info, FeeVault>,
+    fee_vault_token_account: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"fee_vault", &[fee_vault.bump]]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: fee_vault_token_account.clone(),
+                mint: mint.to_account_info(),
+                to: to.clone(),
+                authority: fee_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Helper function to read what the game holds for payouts (lamports or escrowed tokens)
+pub fn escrow_balance<'info>(game: &Account<'info, Game>, token: Option<&TokenEscrow<'info>>) -> Result<u64> {
+    match token {
+        Some(token) => token_balance(&token.escrow),
+        None => Ok(game.to_account_info().lamports()),
+    }
+}
+
+/// Helper function to close the game's emptied escrow token account, returning its rent to `rent_receiver`
+fn close_empty_escrow<'info>(
+    game: &Account<'info, Game>,
+    token: &TokenEscrow<'info>,
+    rent_receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    // Token-2022 refuses to close accounts holding withheld transfer fees; harvesting is permissionless
+    if transfer_fee_config(&token.mint)?.is_some() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token.token_program.clone(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: token.token_program.clone(),
+                    mint: token.mint.clone(),
+                },
+            ),
+            vec![token.escrow.clone()],
+        )?;
+    }
+
+    let game_id = game.game_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"game", game_id.as_ref(), &[game.bump]]];
+    close_account(CpiContext::new_with_signer(
+        token.token_program.clone(),
+        CloseAccount {
+            account: token.escrow.clone(),
+            destination: rent_receiver.clone(),
+            authority: game.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
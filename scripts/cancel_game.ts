@@ -0,0 +1,49 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  validateGameId,
+  getProgram
+} from "./utils";
+
+async function main() {
+  // Get game ID from command line arguments
+  const gameIdArg = process.argv[2];
+  
+  if (!gameIdArg) {
+    console.log("Usage: yarn cancel-game <game_id>");
+    console.log("Example: yarn cancel-game 123");
+    process.exit(1);
+  }
+  
+  try {
+    // Validate game ID
+    const gameId = validateGameId(gameIdArg);
+    
+    // Get program instance
+    const program = getProgram();
+    
+    console.log(`🎮 Cancelling game ${gameId}`);
+    
+    // Cancelling a rematch clears the link on the previous game so a new rematch can be requested
+    const gamePda = (id: anchor.BN) => anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    const game = await program.account.game.fetch(gamePda(new anchor.BN(gameId)));
+    const previousGame = game.previousGameId ? gamePda(game.previousGameId) : null;
+    
+    await program.methods
+      .cancelGame(new anchor.BN(gameId))
+      .accountsPartial({ previousGame })
+      .rpc();
+    
+    console.log("✅ Game cancelled successfully!");
+    console.log("💰 Your wager (minus fee) and the account rent were refunded");
+    
+  } catch (error) {
+    console.error("❌ Error cancelling game:", error);
+    process.exit(1);
+  }
+}
+
+// Run the script
+main().catch(console.error);
@@ -1,19 +1,21 @@
 import * as anchor from "@coral-xyz/anchor";
 import {
   validateWager,
-  formatWagerForProgram,
   getProgram,
   getGlobalState,
-  getPlayerFromWallet
+  getPlayerFromWallet,
+  getReferralAccounts,
+  expectedFee
 } from "./utils";
 
 async function main() {
   // Get wager from command line arguments
   const wagerArg = process.argv[2];
+  const invitedArg = process.argv[3];
   
   if (!wagerArg) {
-    console.log("Usage: yarn create-game <wager>");
-    console.log("Wager options: sol1 (1.0 SOL), sol01 (0.1 SOL), sol001 (0.01 SOL)");
+    console.log("Usage: yarn create-game <wager> [invited_pubkey]");
+    console.log("Wager options: sol1 (1.0 SOL), sol01 (0.1 SOL), sol001 (0.01 SOL) or any SOL amount");
     console.log("Example: yarn create-game sol01");
     process.exit(1);
   }
@@ -22,11 +24,17 @@ async function main() {
     // Validate wager
     const wagerInfo = validateWager(wagerArg);
     
+    // Optional private challenge
+    const invitedPlayer = invitedArg ? new anchor.web3.PublicKey(invitedArg) : null;
+    
     // Get program instance
     const program = getProgram();
     
     console.log(`🎮 Creating game`);
     console.log(`💰 Wager: ${wagerInfo.display}`);
+    if (invitedPlayer) {
+      console.log(`🔒 Private game for: ${invitedPlayer.toString()}`);
+    }
     
     // Get current game counter before creating
     const globalState = await getGlobalState(program);
@@ -34,11 +42,19 @@ async function main() {
     
     console.log(`📊 Current game counter: ${gameId}`);
     
-    // Create game with selected wager amount
-    const wagerEnum = formatWagerForProgram(wagerInfo.variant);
+    // Abort if the fee changes before the transaction lands
+    const maxFee = expectedFee(globalState, wagerInfo.lamports);
+    console.log(`💸 Fee per player: ${maxFee.toNumber() / anchor.web3.LAMPORTS_PER_SOL} SOL`);
     
+    // Create game with selected wager amount (in lamports)
     await program.methods
-      .createGame(wagerEnum as any)
+      .createGame(wagerInfo.lamports, 1, invitedPlayer, null, maxFee) // Single round
+      .accountsPartial({
+        wagerTier: null,       // Free wager within the global min/max
+        fundsVault: null,      // Paid from the wallet
+        ...(await getReferralAccounts(program, program.provider.publicKey!)),
+        playerMintStats: null, // SOL wager
+      })
       .rpc();
     
     console.log("✅ Game created successfully!");
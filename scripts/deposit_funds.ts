@@ -0,0 +1,49 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  validateWager,
+  getProgram,
+  getReferralAccounts
+} from "./utils";
+
+async function main() {
+  // Get amount from command line arguments
+  const amountArg = process.argv[2];
+  
+  if (!amountArg) {
+    console.log("Usage: yarn deposit-funds <amount>");
+    console.log("Example: yarn deposit-funds 0.5");
+    process.exit(1);
+  }
+  
+  try {
+    // Amounts use the same format as wagers (preset name or SOL amount)
+    const amount = validateWager(amountArg);
+    
+    // Get program instance
+    const program = getProgram();
+    
+    console.log(`🏦 Depositing ${amount.display}`);
+    
+    await program.methods
+      .deposit(amount.lamports)
+      .accountsPartial(await getReferralAccounts(program, program.provider.publicKey!))
+      .rpc();
+    
+    const [profilePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player_profile"), program.provider.publicKey!.toBuffer()],
+      program.programId
+    );
+    const profile = await program.account.playerProfile.fetch(profilePda);
+    
+    console.log("✅ Deposit successful!");
+    console.log(`💼 Available funds: ${profile.availableFunds.toNumber() / anchor.web3.LAMPORTS_PER_SOL} SOL`);
+    console.log("💰 Stake games from your balance by passing the funds vault account");
+    
+  } catch (error) {
+    console.error("❌ Error depositing funds:", error);
+    process.exit(1);
+  }
+}
+
+// Run the script
+main().catch(console.error);
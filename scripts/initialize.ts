@@ -4,6 +4,7 @@ import { getProgram } from "./utils";
 async function main() {
   try {
     console.log("🔧 Initializing global state...");
+    console.log("🔑 Must be signed by the program's upgrade authority (the deploy wallet)");
     
     // Use current wallet as fee collector
     const provider = anchor.AnchorProvider.env();
@@ -21,7 +22,7 @@ async function main() {
     console.log("✅ Global state initialized successfully!");
     console.log("🎯 The program is now ready for games!");
     console.log("");
-    console.log("💡 1% of all wagers will be sent to the fee collector address");
+    console.log("💡 Fees (1% by default) accrue in the fee vault; the authority withdraws them to the fee collector");
     
   } catch (error) {
     console.error("❌ Error initializing global state:", error);
@@ -1,32 +1,49 @@
 import * as anchor from "@coral-xyz/anchor";
 import {
   validateGameId,
+  validateMaxFee,
   getProgram,
-  getPlayerFromWallet
+  getPlayerFromWallet,
+  getReferralAccounts
 } from "./utils";
 
 async function main() {
   // Get game ID from command line arguments
   const gameIdArg = process.argv[2];
+  const maxFeeArg = process.argv[3];
   
-  if (!gameIdArg) {
-    console.log("Usage: yarn join-game <game_id>");
-    console.log("Example: yarn join-game 123");
+  if (!gameIdArg || !maxFeeArg) {
+    console.log("Usage: yarn join-game <game_id> <max_fee_sol>");
+    console.log("Example: yarn join-game 123 0.001");
     process.exit(1);
   }
   
   try {
-    // Validate game ID
+    // Validate game ID and the most the player is willing to pay in fees
     const gameId = validateGameId(gameIdArg);
+    const maxFee = validateMaxFee(maxFeeArg);
     
     // Get program instance
     const program = getProgram();
     
+    const [gamePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("game"), new anchor.BN(gameId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const game = await program.account.game.fetch(gamePda);
+    
     console.log(`🎮 Joining game ${gameId}`);
-    console.log(`💰 Will match the existing game's wager amount`);
+    console.log(`💰 Matching wager: ${game.wager.toNumber() / anchor.web3.LAMPORTS_PER_SOL} SOL (fee: ${game.feePerPlayer.toNumber() / anchor.web3.LAMPORTS_PER_SOL} SOL)`);
     
+    // The program rejects the join if the wager differs from what was shown above or the fee exceeds the player's bound
     await program.methods
-      .joinGame(new anchor.BN(gameId))
+      .joinGame(new anchor.BN(gameId), null, game.wager, maxFee)
+      .accountsPartial({
+        joinTicket: null,      // Public game, no password ticket
+        fundsVault: null,      // Paid from the wallet
+        ...(await getReferralAccounts(program, program.provider.publicKey!)),
+        playerMintStats: null, // SOL wager
+      })
       .rpc();
     
     console.log("✅ Successfully joined game!");
@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getProgram } from "./utils";
+
+async function main() {
+  try {
+    console.log("🔧 Migrating a deployment initialized before the fee vault...");
+    console.log("🔑 Must be signed by the global state authority");
+    
+    // Get program instance
+    const program = getProgram();
+    
+    // Grow the global state to the current layout (fails if it is already migrated)
+    await program.methods
+      .migrateGlobalState()
+      .rpc();
+    console.log("✅ Global state migrated");
+    
+    // Fees accrue in the fee vault from now on
+    const [feeVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_vault")],
+      program.programId
+    );
+    const feeVault = await program.provider.connection.getAccountInfo(feeVaultPda);
+    if (feeVault) {
+      console.log("💰 Fee vault already exists");
+    } else {
+      await program.methods
+        .initFeeVault()
+        .rpc();
+      console.log("✅ Fee vault created");
+    }
+    
+    // Grow every player profile still in the deployed layout (the wallet pays the extra rent)
+    const profileDiscriminator = program.idl.accounts.find((account) => account.name === "PlayerProfile")!.discriminator;
+    const profiles = await program.provider.connection.getProgramAccounts(program.programId, {
+      filters: [{ memcmp: { offset: 0, bytes: anchor.utils.bytes.bs58.encode(Buffer.from(profileDiscriminator)) } }],
+    });
+    const legacyProfiles = profiles.filter(({ account }) => account.data.length < program.account.playerProfile.size);
+    for (const { account } of legacyProfiles) {
+      const player = new anchor.web3.PublicKey(account.data.subarray(8, 40));
+      await program.methods
+        .migratePlayerProfile()
+        .accounts({ player })
+        .rpc();
+    }
+    console.log(`✅ ${legacyProfiles.length} player profile(s) migrated`);
+    
+    // Then every game of the first deployment; games still in play need both players' profiles
+    const gameDiscriminator = program.idl.accounts.find((account) => account.name === "Game")!.discriminator;
+    const games = await program.provider.connection.getProgramAccounts(program.programId, {
+      filters: [{ memcmp: { offset: 0, bytes: anchor.utils.bytes.bs58.encode(Buffer.from(gameDiscriminator)) } }],
+    });
+    const legacyGames = games.filter(({ account }) => account.data.length < program.account.game.size);
+    const profilePda = (player: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player_profile"), player.toBuffer()],
+      program.programId
+    )[0];
+    for (const { account } of legacyGames) {
+      // Deployed layout: discriminator, game_id, player1, player2, wager (1 byte), fee_per_player, state
+      const gameId = new anchor.BN(account.data.subarray(8, 16), "le");
+      const player1 = new anchor.web3.PublicKey(account.data.subarray(16, 48));
+      const player2 = new anchor.web3.PublicKey(account.data.subarray(48, 80));
+      const finished = account.data[89] === 3;
+      await program.methods
+        .migrateGame(gameId)
+        .accountsPartial({
+          player1Profile: finished ? null : profilePda(player1),
+          player2Profile: finished || player2.equals(anchor.web3.PublicKey.default) ? null : profilePda(player2),
+        })
+        .rpc();
+    }
+    console.log(`✅ ${legacyGames.length} game(s) migrated`);
+    
+  } catch (error) {
+    console.error("❌ Error migrating:", error);
+    process.exit(1);
+  }
+}
+
+// Run the script
+main().catch(console.error);
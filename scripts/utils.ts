@@ -12,20 +12,11 @@ import * as path from "path";
 export const MOVE_NAMES = ['Rock', 'Paper', 'Scissors'];
 export const MOVE_MAP = { rock: 0, paper: 1, scissors: 2 } as const;
 
-// Wager amount constants
-export const WAGER_AMOUNTS = {
-  Sol1: { lamports: 1_000_000_000, display: '1.0 SOL' },
-  Sol01: { lamports: 100_000_000, display: '0.1 SOL' },
-  Sol001: { lamports: 10_000_000, display: '0.01 SOL' }
-} as const;
-
-export const WAGER_MAP = { 
-  'sol1': 'Sol1', 
-  '1': 'Sol1',
-  'sol01': 'Sol01', 
-  '0.1': 'Sol01',
-  'sol001': 'Sol001',
-  '0.01': 'Sol001'
+// Wager presets (any SOL amount within the on-chain min/max is also accepted)
+export const WAGER_PRESETS = {
+  'sol1': 1_000_000_000,
+  'sol01': 100_000_000,
+  'sol001': 10_000_000
 } as const;
 
 // Helper function to create move hash (move + salt)
@@ -148,6 +139,25 @@ export async function getGlobalState(program: Program<CryptoPvp>) {
   return await program.account.globalState.fetch(globalStatePda);
 }
 
+// Helper to get the player's referral link (needed by new profiles) and, once it records a
+// referrer, that referrer's referral account
+export async function getReferralAccounts(program: Program<CryptoPvp>, player: anchor.web3.PublicKey) {
+  const [referralLink] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("referral_link"), player.toBuffer()],
+    program.programId
+  );
+  const link = await program.account.referralLink.fetchNullable(referralLink);
+  if (!link || link.player.equals(anchor.web3.PublicKey.default)) {
+    return { referralLink, referralAccount: null }; // No referrer
+  }
+
+  const [referralAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("referral"), link.referrer.toBuffer()],
+    program.programId
+  );
+  return { referralLink, referralAccount };
+}
+
 // Helper to format salt for display
 export function formatSalt(salt: Uint8Array): string {
   return Array.from(salt.slice(0, 8)).map(b => b.toString(16).padStart(2, '0')).join('');
@@ -163,26 +173,41 @@ export function saltToHex(salt: Uint8Array): string {
   return Array.from(salt).map(b => b.toString(16).padStart(2, '0')).join('');
 }
 
-// Helper to validate wager argument
-export function validateWager(wagerArg: string): { variant: string; display: string } {
+// Helper to validate wager argument (preset name or SOL amount) and convert it to lamports
+export function validateWager(wagerArg: string): { lamports: anchor.BN; display: string } {
   const normalizedWager = wagerArg.toLowerCase();
   
-  if (!Object.keys(WAGER_MAP).includes(normalizedWager)) {
-    throw new Error(`Invalid wager: ${wagerArg}. Must be one of: sol1, sol01, sol001 (or 1, 0.1, 0.01)`);
+  let lamports: number;
+  if (Object.keys(WAGER_PRESETS).includes(normalizedWager)) {
+    lamports = WAGER_PRESETS[normalizedWager as keyof typeof WAGER_PRESETS];
+  } else {
+    const sol = parseFloat(normalizedWager);
+    if (isNaN(sol) || sol <= 0) {
+      throw new Error(`Invalid wager: ${wagerArg}. Must be sol1, sol01, sol001 or a SOL amount (e.g. 0.25)`);
+    }
+    lamports = Math.round(sol * anchor.web3.LAMPORTS_PER_SOL);
   }
   
-  const variant = WAGER_MAP[normalizedWager as keyof typeof WAGER_MAP];
-  const display = WAGER_AMOUNTS[variant as keyof typeof WAGER_AMOUNTS].display;
-  
-  return { variant, display };
+  const display = `${lamports / anchor.web3.LAMPORTS_PER_SOL} SOL`;
+  return { lamports: new anchor.BN(lamports), display };
 }
 
-// Helper to format wager for program call
-export function formatWagerForProgram(wagerVariant: string) {
-  switch (wagerVariant) {
-    case 'Sol1': return { sol1: {} };
-    case 'Sol01': return { sol01: {} };
-    case 'Sol001': return { sol001: {} };
-    default: throw new Error(`Invalid wager variant: ${wagerVariant}`);
+// Helper to validate the largest fee per player (SOL amount) the user agrees to pay
+export function validateMaxFee(maxFeeArg: string): anchor.BN {
+  const sol = parseFloat(maxFeeArg);
+  if (isNaN(sol) || sol < 0) {
+    throw new Error(`Invalid max fee: ${maxFeeArg}. Must be a SOL amount (e.g. 0.001)`);
   }
-} 
\ No newline at end of file
+  return new anchor.BN(Math.round(sol * anchor.web3.LAMPORTS_PER_SOL));
+}
+
+// Helper to compute the fee per player the program will lock for a SOL wager at current settings
+export function expectedFee(globalState: { feeBps: number; minFee: anchor.BN; maxFee: anchor.BN }, wager: anchor.BN): anchor.BN {
+  const bps = new anchor.BN(globalState.feeBps);
+  let fee = wager.mul(bps).add(new anchor.BN(9_999)).div(new anchor.BN(10_000)); // Rounded up like on-chain
+  fee = anchor.BN.max(fee, globalState.minFee);
+  if (!globalState.maxFee.isZero()) {
+    fee = anchor.BN.min(fee, globalState.maxFee);
+  }
+  return fee;
+}
@@ -0,0 +1,47 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  validateWager,
+  getProgram
+} from "./utils";
+
+async function main() {
+  // Get amount from command line arguments
+  const amountArg = process.argv[2];
+  
+  if (!amountArg) {
+    console.log("Usage: yarn withdraw-funds <amount>");
+    console.log("Example: yarn withdraw-funds 0.5");
+    process.exit(1);
+  }
+  
+  try {
+    // Amounts use the same format as wagers (preset name or SOL amount)
+    const amount = validateWager(amountArg);
+    
+    // Get program instance
+    const program = getProgram();
+    
+    console.log(`🏦 Withdrawing ${amount.display}`);
+    
+    await program.methods
+      .withdraw(amount.lamports)
+      .rpc();
+    
+    const [profilePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player_profile"), program.provider.publicKey!.toBuffer()],
+      program.programId
+    );
+    const profile = await program.account.playerProfile.fetch(profilePda);
+    
+    console.log("✅ Withdrawal successful!");
+    console.log(`💼 Available funds: ${profile.availableFunds.toNumber() / anchor.web3.LAMPORTS_PER_SOL} SOL`);
+    console.log("💰 Lamports returned to your wallet");
+    
+  } catch (error) {
+    console.error("❌ Error withdrawing funds:", error);
+    process.exit(1);
+  }
+}
+
+// Run the script
+main().catch(console.error);
@@ -0,0 +1,50 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { CryptoPvp } from "../target/types/crypto_pvp";
+
+const { Keypair, LAMPORTS_PER_SOL, PublicKey } = anchor.web3;
+const BPF_LOADER_UPGRADEABLE = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+describe("initialize_global_state", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.CryptoPvp as Program<CryptoPvp>;
+
+  // `anchor test` deploys with the provider wallet, which makes it the upgrade authority
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE
+  );
+
+  it("rejects a signer that is not the upgrade authority", async () => {
+    const intruder = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(intruder.publicKey, LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(signature, "confirmed");
+
+    try {
+      await program.methods
+        .initializeGlobalState(intruder.publicKey)
+        .accountsPartial({ authority: intruder.publicKey, programData })
+        .signers([intruder])
+        .rpc();
+      expect.fail("initialize_global_state should have failed");
+    } catch (error) {
+      expect(error).to.be.instanceOf(anchor.AnchorError);
+      expect((error as anchor.AnchorError).error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("lets the upgrade authority initialize", async () => {
+    const authority = provider.wallet.publicKey;
+    await program.methods
+      .initializeGlobalState(authority)
+      .accountsPartial({ authority, programData })
+      .rpc();
+
+    const [globalStatePda] = PublicKey.findProgramAddressSync([Buffer.from("global_state")], program.programId);
+    const globalState = await program.account.globalState.fetch(globalStatePda);
+    expect(globalState.authority.toBase58()).to.equal(authority.toBase58());
+    expect(globalState.feeCollector.toBase58()).to.equal(authority.toBase58());
+  });
+});